@@ -2,40 +2,55 @@ use std::io;
 use std::io::Write;
 
 use cmd::command_handler::CommandHandler;
+use cmd::command_spec::{CommandSpec, ParsedArgs};
 use cmd::cmd::Cmd;
-use cmd::handlers::Quit;
+use cmd::handlers::{Help, Quit};
 
 
-/// CommandHandler that prints out help message
-#[derive(Debug, Default)]
-pub struct Help;
-
-impl CommandHandler for Help {
-    fn execute(&self, _stdout: &mut io::Stdout, _args: String) -> usize {
-        writeln!(_stdout, "Help message").unwrap();
-        1
-    }
-}
-
 /// CommandHandler that emulates the basic bash touch command to create a new file
 #[derive(Debug, Default)]
 pub struct Touch;
 
-impl CommandHandler for Touch {
-    fn execute(&self, _stdout: &mut io::Stdout, _args: String) -> usize {
-        let filename = _args.split_whitespace().next().unwrap_or_default();
-
-        if filename.len() == 0 {
-            println!("Need to specify a filename");
-        } else {
-            let fs_result = std::fs::File::create(filename);
-            match fs_result {
-                Ok(file) => println!("Created file: {:?}", file),
-                Err(_) => println!("Could not create file: {}", filename)
-            }
+impl CommandHandler<io::Stdout> for Touch {
+    fn spec(&self) -> CommandSpec {
+        CommandSpec::new().arg("filename")
+    }
+
+    fn execute(&self, _stdout: &mut io::Stdout, _args: ParsedArgs) -> usize {
+        let filename = _args.positional(0).expect("spec requires filename");
+        let fs_result = std::fs::File::create(filename);
+        match fs_result {
+            Ok(file) => println!("Created file: {:?}", file),
+            Err(_) => println!("Could not create file: {}", filename)
         }
         1
     }
+
+    fn complete(&self, _args: &[&str], word_being_typed: &str) -> Vec<String> {
+        let (dir, prefix) = match word_being_typed.rsplit_once('/') {
+            Some((dir, prefix)) => (dir, prefix),
+            None => (".", word_being_typed),
+        };
+
+        std::fs::read_dir(dir)
+            .map(|entries| {
+                entries
+                    .filter_map(|entry| entry.ok())
+                    .filter_map(|entry| entry.file_name().into_string().ok())
+                    .filter(|name| name.starts_with(prefix))
+                    .map(|name| format!("{dir}/{name}"))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    fn summary(&self) -> &str {
+        "Create a new, empty file"
+    }
+
+    fn usage(&self) -> &str {
+        "touch <filename>"
+    }
 }
 
 
@@ -45,7 +60,7 @@ fn main() -> Result<(), std::io::Error>{
         io::stdout()
     );
 
-    let help = Help::default();
+    let help = Help::new(cmd.registry());
     let hello = Touch::default();
     let quit = Quit::default();
 
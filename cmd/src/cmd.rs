@@ -1,15 +1,73 @@
+use std::cell::RefCell;
+use std::fmt;
 use std::io;
 use std::collections::HashMap;
+use std::rc::Rc;
 
 use crate::command_handler::CommandHandler;
+use crate::error::CmdError;
+use crate::loader::Loader;
+
+/// Error returned when a line of input cannot be tokenized into arguments,
+/// e.g. because it contains an unterminated quote or a dangling escape.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseError(String);
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Configuration for `Cmd::run_interactive`.
+#[derive(Debug, Clone)]
+pub struct InteractiveConfig {
+    /// Prompt string shown before each line is read.
+    pub prompt: String,
+    /// Optional file to load history from at startup and save it to on exit.
+    pub history_path: Option<std::path::PathBuf>,
+}
+
+impl InteractiveConfig {
+    /// New config with `prompt` and no history file.
+    pub fn new(prompt: &str) -> Self {
+        InteractiveConfig {
+            prompt: prompt.to_string(),
+            history_path: None,
+        }
+    }
+
+    /// Persist history to `path`, loading it back on the next `run_interactive` call.
+    pub fn with_history_path(mut self, path: impl Into<std::path::PathBuf>) -> Self {
+        self.history_path = Some(path.into());
+        self
+    }
+}
+
+impl Default for InteractiveConfig {
+    fn default() -> Self {
+        InteractiveConfig::new("(cmd) ")
+    }
+}
+
+/// The shared, boxed-handler map backing a `Cmd`'s dispatch table. Shared via
+/// `Rc<RefCell<..>>` so a `run_interactive` session can hand a completer (and
+/// `handlers::Help`) the same map the interpreter dispatches against.
+pub type Registry<W> = Rc<RefCell<HashMap<String, Box<dyn CommandHandler<W>>>>>;
 
 /// Command interpreter implemented as struct that contains
 /// a handles HashMap of command strings and Boxed CommandHandlers
+///
+/// `handles` is shared via `Rc<RefCell<..>>` so a `run_interactive` session
+/// can hand a completer the same map the interpreter dispatches against.
 #[derive(Debug, Default)]
 pub struct Cmd<R: io::BufRead, W: io::Write>{
-    handles: HashMap<String, Box<dyn CommandHandler<W>>>,
+    handles: Registry<W>,
     stdin: R,
-    stdout: W
+    stdout: W,
+    history: Rc<RefCell<Vec<String>>>,
 }
 
 impl<R: io::BufRead + 'static, W: io::Write + 'static> Cmd<R, W>{
@@ -20,23 +78,39 @@ impl<R: io::BufRead + 'static, W: io::Write + 'static> Cmd<R, W>{
         R: io::Read
     {
         Cmd {
-            handles: HashMap::new(),
+            handles: Rc::new(RefCell::new(HashMap::new())),
             stdin: reader,
-            stdout: writer
+            stdout: writer,
+            history: Rc::new(RefCell::new(Vec::new())),
         }
     }
 
-    pub fn default() -> Cmd<io::BufReader<io::Stdin>, io::Stdout> {
+    /// Convenience constructor wired up to the real process stdin/stdout.
+    pub fn stdio() -> Cmd<io::BufReader<io::Stdin>, io::Stdout> {
         let reader = io::BufReader::new(io::stdin());
         let writer = io::stdout();
 
         Cmd {
-            handles: HashMap::new(),
+            handles: Rc::new(RefCell::new(HashMap::new())),
             stdin: reader,
-            stdout: writer
+            stdout: writer,
+            history: Rc::new(RefCell::new(Vec::new())),
         }
     }
 
+    /// Shared handle to the lines recorded by `run_interactive`, for wiring
+    /// up a `handlers::History` command that reflects the live session.
+    pub fn history(&self) -> Rc<RefCell<Vec<String>>> {
+        Rc::clone(&self.history)
+    }
+
+    /// Shared handle to the command registry, for wiring up a
+    /// `handlers::Help` command that reflects the live set of registered
+    /// commands.
+    pub fn registry(&self) -> Registry<W> {
+        Rc::clone(&self.handles)
+    }
+
     /// Start the command interpreter
     ///
     pub fn run(&mut self) -> Result<(), io::Error>{
@@ -52,59 +126,301 @@ impl<R: io::BufRead + 'static, W: io::Write + 'static> Cmd<R, W>{
             let inputs = inputs.trim();
 
             // separate user input into a command and optional args
-            if !inputs.is_empty() {
-                let (command, args) = self.parse_cmd(inputs);
-
-                // attempt to execute command
-                if let Some(handler) = self.handles.get(&command) {
-                    if let 0 = handler.execute(&mut self.stdout, args) { break; }
-                } else {
-                    self.stdout.write(format!("No command {command}\n").as_bytes())?;
+            if !inputs.is_empty() && self.dispatch_line(inputs)? {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    /// Drive the loop through a `rustyline::Editor` when stdin is a real
+    /// terminal, giving line editing, arrow-key recall, persistent history,
+    /// and Tab-completion of command names and arguments. Falls back to the
+    /// plain `run()` loop otherwise (e.g. piped or redirected input), so
+    /// scripted input keeps working unchanged.
+    ///
+    /// Ctrl-C re-prompts without dispatching; Ctrl-D ends the loop, same as
+    /// a handler returning `0`.
+    pub fn run_interactive(&mut self, config: InteractiveConfig) -> Result<(), io::Error> {
+        use std::io::IsTerminal;
+
+        if !io::stdin().is_terminal() {
+            return self.run();
+        }
+
+        let mut editor: rustyline::Editor<CmdHelper<W>, rustyline::history::DefaultHistory> =
+            rustyline::Editor::new()
+                .map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))?;
+        editor.set_helper(Some(CmdHelper {
+            handles: Rc::clone(&self.handles),
+        }));
+
+        if let Some(path) = &config.history_path {
+            let _ = editor.load_history(path);
+        }
+
+        loop {
+            match editor.readline(&config.prompt) {
+                Ok(line) => {
+                    let _ = editor.add_history_entry(line.as_str());
+                    self.history.borrow_mut().push(line.clone());
+
+                    let line = line.trim();
+                    if !line.is_empty() && self.dispatch_line(line)? {
+                        break;
+                    }
                 }
+                Err(rustyline::error::ReadlineError::Interrupted) => continue,
+                Err(rustyline::error::ReadlineError::Eof) => break,
+                Err(e) => return Err(io::Error::new(io::ErrorKind::Other, e.to_string())),
             }
         }
+
+        if let Some(path) = &config.history_path {
+            let _ = editor.save_history(path);
+        }
+
         Ok(())
     }
 
+    /// Parse and dispatch a single already-trimmed, non-empty line.
+    /// Returns `Ok(true)` when the interpreter loop should break.
+    fn dispatch_line(&mut self, inputs: &str) -> Result<bool, io::Error> {
+        let (command, args) = match self.parse_cmd(inputs) {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                self.stdout.write(format!("Parse error: {e}\n").as_bytes())?;
+                return Ok(false);
+            }
+        };
+
+        // attempt to execute command
+        let handles = self.handles.borrow();
+        if let Some(handler) = handles.get(&command) {
+            match handler.spec().parse(args) {
+                Ok(parsed) => return Ok(handler.execute(&mut self.stdout, parsed) == 0),
+                Err(e) => {
+                    self.stdout.write(format!("{e}\n").as_bytes())?;
+                }
+            }
+        } else {
+            self.stdout.write(format!("No command {command}\n").as_bytes())?;
+        }
+        Ok(false)
+    }
+
+    /// Run every line from `loader` through the same dispatch logic as
+    /// `run()`, without an interactive prompt. A handler returning `0` (as
+    /// `Quit` does) ends the script early.
+    ///
+    /// Errors are collected with their source line number rather than
+    /// aborting the script, so a caller can report *where* a batch failed.
+    /// A line prefixed with `@` in the original source has its errors
+    /// ignored, same as `make`'s convention.
+    pub fn run_loader(&mut self, loader: &Loader) -> Vec<(usize, CmdError)> {
+        let mut errors = Vec::new();
+        for line in loader.lines() {
+            match self.dispatch_for_script(&line.text, line.line_no) {
+                Ok(true) => break,
+                Ok(false) => {}
+                Err(e) => {
+                    if !line.ignore_errors {
+                        errors.push((line.line_no, e));
+                    }
+                }
+            }
+        }
+        errors
+    }
+
+    /// Parse and dispatch a single line for `run_loader`, without printing:
+    /// callers get a structured `CmdError` instead of a message written to
+    /// stdout (only `CmdError::UnknownCommand` records `line_no` itself).
+    /// Returns `Ok(true)` when the script should stop early.
+    fn dispatch_for_script(&mut self, inputs: &str, line_no: usize) -> Result<bool, CmdError> {
+        let (command, args) = self
+            .parse_cmd(inputs)
+            .map_err(|e| CmdError::Parse(e.to_string()))?;
+
+        let handles = self.handles.borrow();
+        let handler = handles
+            .get(&command)
+            .ok_or_else(|| CmdError::UnknownCommand {
+                name: command.clone(),
+                line: line_no,
+            })?;
+        let parsed = handler.spec().parse(args).map_err(CmdError::Parse)?;
+        Ok(handler.execute(&mut self.stdout, parsed) == 0)
+    }
 
     /// Insert new command into the Cmd handles HashMap
     ///
     /// ## Note: Will not overwrite existing handles.
     pub fn add_cmd(&mut self, name: String, handler: Box<dyn CommandHandler<W>>) -> Result<(), io::Error> {
-        if let Some(_) = self.handles.get(&name) {
+        if self.handles.borrow().contains_key(&name) {
             self.stdout.write(format!("Warning: Command with handle {name} already exists.").as_bytes())?;
         } else {
-        self.handles.insert(name, handler);
+            self.handles.borrow_mut().insert(name, handler);
         }
         Ok(())
     }
 
-    fn parse_cmd(&self, line: &str) -> (String, String) {
-        let mut words = line.split_whitespace();
-        let command = words.next().unwrap_or_default().to_string();
-        let args: String = words.collect::<Vec<_>>().join(" ");
-        (command, args)
+    /// Split `line` into a command name and its fully-resolved argument tokens,
+    /// honoring single quotes, double quotes, and backslash escapes (POSIX/shell-words
+    /// style). Returns a `ParseError` if a quote or escape is left unterminated.
+    fn parse_cmd(&self, line: &str) -> Result<(String, Vec<String>), ParseError> {
+        let mut tokens = tokenize(line)?;
+        if tokens.is_empty() {
+            return Ok((String::new(), Vec::new()));
+        }
+        let command = tokens.remove(0);
+        Ok((command, tokens))
     }
 
     #[cfg(test)]
-    fn get_cmd(&self, key: String) -> Option<&Box<dyn CommandHandler<W>>> {
-        self.handles.get(&key)
+    fn get_cmd(&self, key: String) -> Option<std::cell::Ref<'_, Box<dyn CommandHandler<W>>>> {
+        std::cell::Ref::filter_map(self.handles.borrow(), |handles| handles.get(&key)).ok()
+    }
+}
+
+/// `rustyline` helper backing `Cmd::run_interactive`'s Tab-completion: offers
+/// registered command names on the first word, then delegates to that
+/// command's own `CommandHandler::complete` for its arguments.
+struct CmdHelper<W: io::Write + 'static> {
+    handles: Registry<W>,
+}
+
+impl<W: io::Write + 'static> rustyline::completion::Completer for CmdHelper<W> {
+    type Candidate = String;
+
+    fn complete(
+        &self,
+        line: &str,
+        pos: usize,
+        _ctx: &rustyline::Context<'_>,
+    ) -> rustyline::Result<(usize, Vec<String>)> {
+        Ok(complete_line(&self.handles.borrow(), line, pos))
+    }
+}
+
+impl<W: io::Write + 'static> rustyline::hint::Hinter for CmdHelper<W> {
+    type Hint = String;
+}
+
+impl<W: io::Write + 'static> rustyline::highlight::Highlighter for CmdHelper<W> {}
+
+impl<W: io::Write + 'static> rustyline::validate::Validator for CmdHelper<W> {}
+
+impl<W: io::Write + 'static> rustyline::Helper for CmdHelper<W> {}
+
+/// Complete the word under the cursor at byte offset `pos` in `line`: the
+/// sorted, prefix-matching command names when the cursor is in the first
+/// word, otherwise the matching command's own `CommandHandler::complete`.
+fn complete_line<W: io::Write + 'static>(
+    handles: &HashMap<String, Box<dyn CommandHandler<W>>>,
+    line: &str,
+    pos: usize,
+) -> (usize, Vec<String>) {
+    let start = line[..pos]
+        .rfind(char::is_whitespace)
+        .map(|i| i + 1)
+        .unwrap_or(0);
+    let word = &line[start..pos];
+    let before = line[..start].trim_start();
+
+    if before.is_empty() {
+        let mut matches: Vec<String> = handles
+            .keys()
+            .filter(|name| name.starts_with(word))
+            .cloned()
+            .collect();
+        matches.sort();
+        return (start, matches);
+    }
+
+    let mut tokens = before.split_whitespace();
+    let command = tokens.next().unwrap_or_default();
+    let args: Vec<&str> = tokens.collect();
+
+    match handles.get(command) {
+        Some(handler) => {
+            let mut matches = handler.complete(&args, word);
+            matches.sort();
+            (start, matches)
+        }
+        None => (start, Vec::new()),
     }
 }
 
+/// Tokenize a line the way a POSIX shell would: unquoted whitespace separates
+/// tokens, single quotes take everything literally, double quotes allow only
+/// `\"` and `\\` escapes (any other backslash is kept as-is), and an unquoted
+/// backslash escapes the next char.
+fn tokenize(line: &str) -> Result<Vec<String>, ParseError> {
+    let mut tokens = Vec::new();
+    let mut token = String::new();
+    let mut in_single = false;
+    let mut in_double = false;
+    let mut escape = false;
+    let mut quoted = false;
+
+    for c in line.chars() {
+        if escape {
+            if in_double && c != '"' && c != '\\' {
+                token.push('\\');
+            }
+            token.push(c);
+            escape = false;
+            continue;
+        }
+
+        match c {
+            '\\' if !in_single => escape = true,
+            '\'' if !in_double => {
+                in_single = !in_single;
+                quoted = true;
+            }
+            '"' if !in_single => {
+                in_double = !in_double;
+                quoted = true;
+            }
+            c if c.is_whitespace() && !in_single && !in_double => {
+                if !token.is_empty() || quoted {
+                    tokens.push(std::mem::take(&mut token));
+                    quoted = false;
+                }
+            }
+            c => token.push(c),
+        }
+    }
+
+    if escape {
+        return Err(ParseError("dangling escape".to_string()));
+    }
+    if in_single || in_double {
+        return Err(ParseError("unterminated quote".to_string()));
+    }
+    if !token.is_empty() || quoted {
+        tokens.push(token);
+    }
+
+    Ok(tokens)
+}
+
 #[cfg(test)]
 mod tests {
     use std::{any::Any, io::BufRead};
     use std::io::{self, BufReader, Write};
 
     use super::*;
+    use crate::command_spec::ParsedArgs;
     use crate::handlers::Quit;
 
     #[derive(Debug, Default)]
     pub struct Greeting { }
 
     impl<W: io::Write> CommandHandler<W> for Greeting {
-        fn execute(&self, stdout: &mut W, _args: String) -> usize {
+        fn execute(&self, stdout: &mut W, _args: ParsedArgs) -> usize {
             write!(stdout, "Hello there!").unwrap();
             1
         }
@@ -161,14 +477,17 @@ mod tests {
     fn test_add_cmd() -> Result<(), io::Error> {
         let mut app = setup();
 
-        let h = app.get_cmd(String::from("greet"));
+        {
+            let h = app.get_cmd(String::from("greet"));
 
-        // Verify that the key-value pair exists in the HashMap
-        assert!(h.is_some());
+            // Verify that the key-value pair exists in the HashMap
+            assert!(h.is_some());
 
-        // Verify the value can cast down to Greeting
-        let it: &dyn Any = h.unwrap().as_any();
-        assert!(!it.downcast_ref::<Greeting>().is_none());
+            // Verify the value can cast down to Greeting
+            let h = h.unwrap();
+            let it: &dyn Any = h.as_any();
+            assert!(!it.downcast_ref::<Greeting>().is_none());
+        }
 
         // Verify message is printed out when a handle with existing name is added
         app.add_cmd("greet".to_string(), Box::new(Greeting {} ))?;
@@ -200,7 +519,74 @@ mod tests {
     fn test_parse_cmd(){
         let app = setup();
         let line = "command arg1 arg2";
-        assert_eq!(app.parse_cmd(line), ("command".to_string(), "arg1 arg2".to_string()))
+        assert_eq!(
+            app.parse_cmd(line).unwrap(),
+            ("command".to_string(), vec!["arg1".to_string(), "arg2".to_string()])
+        )
+    }
+
+    #[test]
+    fn test_parse_cmd_empty_line() {
+        let app = setup();
+        assert_eq!(app.parse_cmd("").unwrap(), (String::new(), Vec::new()));
+    }
+
+    #[test]
+    fn test_parse_cmd_quoted_arg_with_space() {
+        let app = setup();
+        let line = r#"touch "my file.txt""#;
+        assert_eq!(
+            app.parse_cmd(line).unwrap(),
+            ("touch".to_string(), vec!["my file.txt".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_parse_cmd_single_quotes_are_literal() {
+        let app = setup();
+        let line = r#"echo 'a\b'"#;
+        assert_eq!(
+            app.parse_cmd(line).unwrap(),
+            ("echo".to_string(), vec![r"a\b".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_parse_cmd_double_quote_escapes() {
+        let app = setup();
+        let line = r#"echo "a\"b""#;
+        assert_eq!(
+            app.parse_cmd(line).unwrap(),
+            ("echo".to_string(), vec![r#"a"b"#.to_string()])
+        );
+    }
+
+    #[test]
+    fn test_parse_cmd_empty_quoted_arg() {
+        let app = setup();
+        let line = r#"echo """#;
+        assert_eq!(
+            app.parse_cmd(line).unwrap(),
+            ("echo".to_string(), vec![String::new()])
+        );
+    }
+
+    #[test]
+    fn test_parse_cmd_unterminated_quote_is_error() {
+        let app = setup();
+        assert_eq!(
+            app.parse_cmd(r#"echo "unterminated"#).unwrap_err().to_string(),
+            "unterminated quote"
+        );
+    }
+
+    #[test]
+    fn test_parse_cmd_dangling_escape_is_error() {
+        let app = setup();
+        assert_eq!(
+            app.parse_cmd(r"echo \").unwrap_err().to_string(),
+            "dangling escape"
+        );
     }
 
     #[test]
@@ -257,9 +643,96 @@ mod tests {
     }
 
     #[test]
-    fn test_default() {
-        let app = Cmd::<io::BufReader<io::Stdin>, io::Stdout>::default();
-        assert!(app.handles.is_empty())
+    fn test_stdio() {
+        let app = Cmd::<io::BufReader<io::Stdin>, io::Stdout>::stdio();
+        assert!(app.handles.borrow().is_empty())
+    }
+
+    #[test]
+    fn test_registry_reflects_live_additions() {
+        let mut app = setup();
+        let registry = app.registry();
+        assert!(registry.borrow().contains_key("greet"));
+
+        app.add_cmd("waves".to_string(), Box::new(Greeting::default()))
+            .unwrap();
+        assert!(registry.borrow().contains_key("waves"));
+    }
+
+    #[test]
+    fn test_run_loader_dispatches_each_line() {
+        let mut app = setup();
+        let loader = Loader::from_source("greet world\n# a comment\n\nquit\ngreet unreachable");
+
+        let errors = app.run_loader(&loader);
+
+        assert!(errors.is_empty());
+        assert_eq!(
+            String::from_utf8(app.stdout).unwrap(),
+            "Hello there!"
+        );
+    }
+
+    #[test]
+    fn test_run_loader_collects_errors_with_line_numbers() {
+        let mut app = setup();
+        let loader = Loader::from_source("bogus\ngreet world\nquit");
+
+        let errors = app.run_loader(&loader);
+
+        assert_eq!(errors.len(), 1);
+        let (line_no, err) = &errors[0];
+        assert_eq!(*line_no, 1);
+        assert_eq!(err.to_string(), "line 1: no command bogus");
+    }
+
+    #[test]
+    fn test_run_loader_at_prefix_ignores_errors() {
+        let mut app = setup();
+        let loader = Loader::from_source("@bogus\ngreet world\nquit");
+
+        let errors = app.run_loader(&loader);
+
+        assert!(errors.is_empty());
+        assert_eq!(String::from_utf8(app.stdout).unwrap(), "Hello there!");
+    }
+
+    #[test]
+    fn test_complete_line_command_name_prefix() {
+        let mut handles: HashMap<String, Box<dyn CommandHandler<Vec<u8>>>> = HashMap::new();
+        handles.insert("quit".to_string(), Box::new(Quit::default()));
+        handles.insert("quiet".to_string(), Box::new(Quit::default()));
+        handles.insert("greet".to_string(), Box::new(Greeting::default()));
+
+        let (start, matches) = complete_line(&handles, "qui", 3);
+
+        assert_eq!(start, 0);
+        assert_eq!(matches, vec!["quiet".to_string(), "quit".to_string()]);
+    }
+
+    #[test]
+    fn test_complete_line_delegates_to_handler() {
+        #[derive(Debug, Default)]
+        struct Completing;
+
+        impl<W: io::Write> CommandHandler<W> for Completing {
+            fn execute(&self, _stdout: &mut W, _args: ParsedArgs) -> usize {
+                1
+            }
+
+            fn complete(&self, _args: &[&str], word_being_typed: &str) -> Vec<String> {
+                vec![format!("{word_being_typed}ed")]
+            }
+        }
+
+        let mut handles: HashMap<String, Box<dyn CommandHandler<Vec<u8>>>> = HashMap::new();
+        handles.insert("run".to_string(), Box::new(Completing));
+
+        let line = "run jump";
+        let (start, matches) = complete_line(&handles, line, line.len());
+
+        assert_eq!(start, 4);
+        assert_eq!(matches, vec!["jumped".to_string()]);
     }
 }
 
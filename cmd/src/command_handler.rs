@@ -1,5 +1,7 @@
 use std::{any::Any, fmt};
 
+use crate::command_spec::{CommandSpec, ParsedArgs};
+
 // TODO: Look into why this works!
 pub trait AToAny: 'static {
     fn as_any(&self) -> &dyn Any;
@@ -14,66 +16,103 @@ impl<T: 'static> AToAny for T {
 
 /// Interface for creating new commands
 ///
+/// `args` is the result of validating the tokenized input against this
+/// handler's `spec()`. Handlers that don't override `spec()` get
+/// `CommandSpec::any()`, which performs no validation and hands back every
+/// raw token as a positional argument.
+///
+/// Returning `0` from `execute` instructs the `Cmd::run()` loop to break.
+///
 /// # Examples
 ///
 /// ```rust
-///    #[derive(Debug, Default)]
-///    pub struct Greeting { name: Option<String> }
+///    use std::io;
 ///
-///    impl command_handler::CommandHandler for Greeting {
-///        fn execute(&self) {
-///            match &self.name {
-///                Some(n) => println!("Welcome {}, a cli command interpreter", n),
-///                None => println!("Welcome! This is a cli command interpreter"),
-///            }
-///        }
+///    use cmd::command_handler;
+///    use cmd::command_spec;
 ///
-///        fn add_attr(&mut self, attr: &str) {
-///            self.name = Some(String::from(attr));
-///        }
-///    }
-///
-///    /// CommandHandler that prints out help message
 ///    #[derive(Debug, Default)]
-///    pub struct Help {}
+///    pub struct Greeting;
 ///
-///    impl command_handler::CommandHandler for Help {
-///        fn execute(&self) {
-///            println!("Help message");
+///    impl<W: io::Write> command_handler::CommandHandler<W> for Greeting {
+///        fn execute(&self, stdout: &mut W, args: command_spec::ParsedArgs) -> usize {
+///            match args.positional(0) {
+///                Some(name) => writeln!(stdout, "Welcome {}, a cli command interpreter", name).unwrap(),
+///                None => writeln!(stdout, "Welcome! This is a cli command interpreter").unwrap(),
+///            }
+///            1
 ///        }
-///
-///        fn add_attr(&mut self, _attr: &str) { }
-///
 ///    }
 ///
 ///    /// CommandHandler that emulates the basic bash touch command to create a new file
 ///    #[derive(Debug, Default)]
-///    pub struct Touch { filename: String }
+///    pub struct Touch;
 ///
-///    impl command_handler::CommandHandler for Touch {
-///        fn execute(&self) {
-///            match self.filename.as_str() {
-///                "" => println!("A filename arg needs to be provided!"),
-///                _ => {
-///                    let fs_result = fs::File::create(&self.filename);
-///                    match fs_result {
-///                        Ok(file) => println!("Created file: {:?}", file),
-///                        Err(_) => println!("Could not create file: {}", self.filename)
-///                    }
-///                }
-///            }
+///    impl<W: io::Write> command_handler::CommandHandler<W> for Touch {
+///        fn spec(&self) -> command_spec::CommandSpec {
+///            command_spec::CommandSpec::new().arg("filename")
 ///        }
 ///
-///        fn add_attr(&mut self, attr: &str) {
-///            self.filename = attr
-///                .split(" ")
-///                .next()
-///                .unwrap_or_default()
-///                .to_string();
+///        fn execute(&self, stdout: &mut W, args: command_spec::ParsedArgs) -> usize {
+///            let filename = args.positional(0).expect("spec requires filename");
+///            let fs_result = std::fs::File::create(filename);
+///            match fs_result {
+///                Ok(file) => writeln!(stdout, "Created file: {:?}", file).unwrap(),
+///                Err(_) => writeln!(stdout, "Could not create file: {}", filename).unwrap(),
+///            }
+///            1
 ///        }
 ///    }
 /// ```
-pub trait CommandHandler: fmt::Debug + AToAny {
+pub trait CommandHandler<W: std::io::Write>: fmt::Debug + AToAny {
     /// Required method to execute a command
-    fn execute(&self, _args: String);
+    ///
+    /// `args` holds the result of matching the raw tokens against `spec()`.
+    fn execute(&self, stdout: &mut W, args: ParsedArgs) -> usize;
+
+    /// Declarative schema of this command's positional arguments and flags,
+    /// used by `Cmd::run` to parse and validate raw tokens before dispatch.
+    ///
+    /// Defaults to `CommandSpec::any()`, which performs no validation and
+    /// passes every raw token through as a positional argument.
+    fn spec(&self) -> CommandSpec {
+        CommandSpec::any()
+    }
+
+    /// Tab-completion candidates for `word_being_typed`, given the `args`
+    /// already typed before it (not including the command name itself).
+    ///
+    /// Used by `Cmd::run_interactive`'s completer; defaults to no
+    /// completions, so only handlers that want it (e.g. completing
+    /// filesystem paths) need to implement it.
+    fn complete(&self, _args: &[&str], _word_being_typed: &str) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// Display name to show in `handlers::Help`'s listing, if different from
+    /// the key this handler is registered under (e.g. a subcommand exposed
+    /// under an alias). Defaults to `None`, meaning the registered name is
+    /// used as-is.
+    fn name_hint(&self) -> Option<&str> {
+        None
+    }
+
+    /// One-line description shown next to this command in `handlers::Help`'s
+    /// listing. Defaults to empty.
+    fn summary(&self) -> &str {
+        ""
+    }
+
+    /// Full usage text shown by `help <command>`. Defaults to empty.
+    fn usage(&self) -> &str {
+        ""
+    }
+
+    /// This command's own subcommands and their summaries, for a command
+    /// that is itself a group (see `handlers::Group`). Defaults to empty,
+    /// since most commands aren't groups; `handlers::Help` recurses into
+    /// these to list `parent child` pairs.
+    fn subcommands(&self) -> Vec<(String, String)> {
+        Vec::new()
+    }
 }
\ No newline at end of file
@@ -0,0 +1,295 @@
+use std::collections::HashMap;
+
+/// How many times a positional argument may appear.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Arity {
+    One,
+    Optional,
+    Repeated,
+}
+
+#[derive(Debug, Clone)]
+struct PositionalSpec {
+    name: String,
+    arity: Arity,
+}
+
+#[derive(Debug, Clone)]
+struct FlagSpec {
+    name: String,
+    short: Option<char>,
+    takes_value: bool,
+}
+
+/// Declarative schema of positional arguments and named flags a `CommandHandler`
+/// expects, modeled on `xflags`. `Cmd::run` parses the raw, tokenized input
+/// against this schema before dispatching to the handler, so the handler
+/// receives a validated `ParsedArgs` instead of a raw token list.
+#[derive(Debug, Clone, Default)]
+pub struct CommandSpec {
+    positionals: Vec<PositionalSpec>,
+    flags: Vec<FlagSpec>,
+    any: bool,
+}
+
+impl CommandSpec {
+    /// A schema with no declared positionals or flags: every raw token is
+    /// handed back as a positional, unvalidated. This is the default for
+    /// handlers that haven't opted into typed parsing.
+    pub fn any() -> Self {
+        CommandSpec {
+            any: true,
+            ..Default::default()
+        }
+    }
+
+    /// A schema with no positionals or flags declared yet; build it up with
+    /// `arg`, `optional_arg`, `repeated_arg`, `opt_flag`, and `val_flag`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Declare a required positional argument.
+    pub fn arg(mut self, name: &str) -> Self {
+        self.positionals.push(PositionalSpec {
+            name: name.to_string(),
+            arity: Arity::One,
+        });
+        self
+    }
+
+    /// Declare an optional positional argument.
+    pub fn optional_arg(mut self, name: &str) -> Self {
+        self.positionals.push(PositionalSpec {
+            name: name.to_string(),
+            arity: Arity::Optional,
+        });
+        self
+    }
+
+    /// Declare a positional argument that consumes all remaining tokens.
+    /// Should be the last positional declared.
+    pub fn repeated_arg(mut self, name: &str) -> Self {
+        self.positionals.push(PositionalSpec {
+            name: name.to_string(),
+            arity: Arity::Repeated,
+        });
+        self
+    }
+
+    /// Declare a boolean `--name`/`-c` flag.
+    pub fn opt_flag(mut self, name: &str, short: char) -> Self {
+        self.flags.push(FlagSpec {
+            name: name.to_string(),
+            short: Some(short),
+            takes_value: false,
+        });
+        self
+    }
+
+    /// Declare a value-taking `--name <value>`/`-c <value>` flag.
+    pub fn val_flag(mut self, name: &str, short: char) -> Self {
+        self.flags.push(FlagSpec {
+            name: name.to_string(),
+            short: Some(short),
+            takes_value: true,
+        });
+        self
+    }
+
+    /// Parse `tokens` against this schema, routing `--name`/`-c` into flags
+    /// and everything else into positionals, in declaration order.
+    ///
+    /// Returns `Err` with a message describing the first missing required
+    /// argument or unrecognized flag.
+    pub fn parse(&self, tokens: Vec<String>) -> Result<ParsedArgs, String> {
+        if self.any {
+            return Ok(ParsedArgs {
+                positionals: tokens,
+                flags: HashMap::new(),
+                values: HashMap::new(),
+            });
+        }
+
+        let mut positionals = Vec::new();
+        let mut flags = HashMap::new();
+        let mut values = HashMap::new();
+
+        let mut tokens = tokens.into_iter();
+        while let Some(token) = tokens.next() {
+            if let Some(name) = token.strip_prefix("--") {
+                let spec = self
+                    .find_flag_long(name)
+                    .ok_or_else(|| format!("Unknown flag --{name}"))?;
+                if spec.takes_value {
+                    let value = tokens
+                        .next()
+                        .ok_or_else(|| format!("Missing value for --{name}"))?;
+                    values.insert(spec.name.clone(), value);
+                } else {
+                    flags.insert(spec.name.clone(), true);
+                }
+            } else if token.starts_with('-') && token.len() > 1 {
+                let short = token[1..].chars().next().unwrap();
+                let spec = self
+                    .find_flag_short(short)
+                    .ok_or_else(|| format!("Unknown flag -{short}"))?;
+                if spec.takes_value {
+                    let value = tokens
+                        .next()
+                        .ok_or_else(|| format!("Missing value for -{short}"))?;
+                    values.insert(spec.name.clone(), value);
+                } else {
+                    flags.insert(spec.name.clone(), true);
+                }
+            } else {
+                positionals.push(token);
+            }
+        }
+
+        let mut positionals = positionals.into_iter();
+        let mut parsed_positionals = Vec::new();
+        for spec in &self.positionals {
+            match spec.arity {
+                Arity::One => {
+                    let value = positionals
+                        .next()
+                        .ok_or_else(|| format!("Missing required argument {}", spec.name))?;
+                    parsed_positionals.push(value);
+                }
+                Arity::Optional => parsed_positionals.extend(positionals.next()),
+                Arity::Repeated => parsed_positionals.extend(positionals.by_ref()),
+            }
+        }
+        // Anything left over once every declared positional is satisfied is
+        // passed through rather than rejected, so trailing free-form tokens
+        // (e.g. for a handler spec that only declares its required args)
+        // still reach the handler.
+        parsed_positionals.extend(positionals);
+
+        Ok(ParsedArgs {
+            positionals: parsed_positionals,
+            flags,
+            values,
+        })
+    }
+
+    fn find_flag_long(&self, name: &str) -> Option<&FlagSpec> {
+        self.flags.iter().find(|f| f.name == name)
+    }
+
+    fn find_flag_short(&self, short: char) -> Option<&FlagSpec> {
+        self.flags.iter().find(|f| f.short == Some(short))
+    }
+}
+
+/// Result of matching raw tokens against a `CommandSpec`.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct ParsedArgs {
+    positionals: Vec<String>,
+    flags: HashMap<String, bool>,
+    values: HashMap<String, String>,
+}
+
+impl ParsedArgs {
+    /// The positional argument at `idx`, if present.
+    pub fn positional(&self, idx: usize) -> Option<&str> {
+        self.positionals.get(idx).map(String::as_str)
+    }
+
+    /// All positional arguments, in order.
+    pub fn positionals(&self) -> &[String] {
+        &self.positionals
+    }
+
+    /// Whether the boolean flag `name` was passed.
+    pub fn flag(&self, name: &str) -> bool {
+        *self.flags.get(name).unwrap_or(&false)
+    }
+
+    /// The value passed to the value-taking flag `name`, if any.
+    pub fn value(&self, name: &str) -> Option<&str> {
+        self.values.get(name).map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_any_passes_through_raw_tokens() {
+        let spec = CommandSpec::any();
+        let parsed = spec
+            .parse(vec!["a".to_string(), "-f".to_string(), "b".to_string()])
+            .unwrap();
+        assert_eq!(parsed.positionals(), &["a", "-f", "b"]);
+    }
+
+    #[test]
+    fn test_required_arg() {
+        let spec = CommandSpec::new().arg("path");
+        let parsed = spec.parse(vec!["file.txt".to_string()]).unwrap();
+        assert_eq!(parsed.positional(0), Some("file.txt"));
+    }
+
+    #[test]
+    fn test_missing_required_arg_is_error() {
+        let spec = CommandSpec::new().arg("path");
+        assert_eq!(
+            spec.parse(vec![]).unwrap_err(),
+            "Missing required argument path"
+        );
+    }
+
+    #[test]
+    fn test_optional_arg_may_be_absent() {
+        let spec = CommandSpec::new().optional_arg("path");
+        let parsed = spec.parse(vec![]).unwrap();
+        assert_eq!(parsed.positional(0), None);
+    }
+
+    #[test]
+    fn test_repeated_arg_collects_remaining() {
+        let spec = CommandSpec::new().repeated_arg("files");
+        let parsed = spec
+            .parse(vec!["a".to_string(), "b".to_string(), "c".to_string()])
+            .unwrap();
+        assert_eq!(parsed.positionals(), &["a", "b", "c"]);
+    }
+
+    #[test]
+    fn test_opt_flag_long_and_short() {
+        let spec = CommandSpec::new().opt_flag("force", 'f');
+        assert!(spec.parse(vec!["--force".to_string()]).unwrap().flag("force"));
+        assert!(spec.parse(vec!["-f".to_string()]).unwrap().flag("force"));
+        assert!(!spec.parse(vec![]).unwrap().flag("force"));
+    }
+
+    #[test]
+    fn test_val_flag_consumes_next_token() {
+        let spec = CommandSpec::new().val_flag("count", 'n');
+        let parsed = spec
+            .parse(vec!["--count".to_string(), "3".to_string()])
+            .unwrap();
+        assert_eq!(parsed.value("count"), Some("3"));
+    }
+
+    #[test]
+    fn test_unknown_flag_is_error() {
+        let spec = CommandSpec::new();
+        assert_eq!(
+            spec.parse(vec!["--bogus".to_string()]).unwrap_err(),
+            "Unknown flag --bogus"
+        );
+    }
+
+    #[test]
+    fn test_missing_value_for_val_flag_is_error() {
+        let spec = CommandSpec::new().val_flag("count", 'n');
+        assert_eq!(
+            spec.parse(vec!["--count".to_string()]).unwrap_err(),
+            "Missing value for --count"
+        );
+    }
+}
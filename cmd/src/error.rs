@@ -0,0 +1,32 @@
+use std::fmt;
+use std::io;
+
+/// Unified error type for batch dispatch via `Cmd::run_loader`: an I/O
+/// failure reading a script, a tokenizer/argument-spec parse failure, or a
+/// line naming a command that isn't registered.
+#[derive(Debug)]
+pub enum CmdError {
+    Io(io::Error),
+    Parse(String),
+    UnknownCommand { name: String, line: usize },
+}
+
+impl fmt::Display for CmdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CmdError::Io(e) => write!(f, "{e}"),
+            CmdError::Parse(msg) => write!(f, "{msg}"),
+            CmdError::UnknownCommand { name, line } => {
+                write!(f, "line {line}: no command {name}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for CmdError {}
+
+impl From<io::Error> for CmdError {
+    fn from(e: io::Error) -> Self {
+        CmdError::Io(e)
+    }
+}
@@ -1,5 +1,12 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt;
 use std::io;
+use std::rc::Rc;
+
+use crate::cmd::Registry;
 use crate::command_handler::CommandHandler;
+use crate::command_spec::{CommandSpec, ParsedArgs};
 
 /// Ready-to-use command to quit the cmd loop
 ///
@@ -8,18 +15,398 @@ use crate::command_handler::CommandHandler;
 pub struct Quit {}
 
 impl<W: io::Write> CommandHandler<W> for Quit {
-    fn execute(&self, _cmd: &mut W, _args: String) -> usize {
+    fn execute(&self, _cmd: &mut W, _args: ParsedArgs) -> usize {
         0
     }
+
+    fn summary(&self) -> &str {
+        "Exit the interpreter"
+    }
+
+    fn usage(&self) -> &str {
+        "quit"
+    }
+}
+
+/// Ready-to-use command that prints the lines recorded by `Cmd::run_interactive`,
+/// one per line, numbered from 1. Wire it up with the handle returned by
+/// `Cmd::history()` so it reflects the live session:
+///
+/// ```rust,ignore
+/// let history = cmd.history();
+/// cmd.add_cmd(String::from("history"), Box::new(handlers::History::new(history)))?;
+/// ```
+#[derive(Debug, Default)]
+pub struct History {
+    entries: Rc<RefCell<Vec<String>>>,
+}
+
+impl History {
+    pub fn new(entries: Rc<RefCell<Vec<String>>>) -> Self {
+        History { entries }
+    }
+}
+
+impl<W: io::Write> CommandHandler<W> for History {
+    fn execute(&self, stdout: &mut W, _args: ParsedArgs) -> usize {
+        for (i, line) in self.entries.borrow().iter().enumerate() {
+            writeln!(stdout, "{:>4}  {}", i + 1, line).unwrap();
+        }
+        1
+    }
+
+    fn summary(&self) -> &str {
+        "Show the lines recorded so far in this session"
+    }
+
+    fn usage(&self) -> &str {
+        "history"
+    }
+}
+
+/// Ready-to-use command that lists every registered command with its
+/// `CommandHandler::summary`, or shows `CommandHandler::usage` for one
+/// command. Wire it up with the handle returned by `Cmd::registry()` so it
+/// reflects the live set of registered commands:
+///
+/// ```rust,ignore
+/// let registry = cmd.registry();
+/// cmd.add_cmd(String::from("help"), Box::new(handlers::Help::new(registry)))?;
+/// ```
+#[derive(Default)]
+pub struct Help<W: io::Write + 'static> {
+    registry: Registry<W>,
+}
+
+/// Hand-rolled instead of derived: `CommandHandler<W>: Debug` must hold for
+/// every `W: io::Write`, but a derive would add a spurious `W: Debug` bound.
+impl<W: io::Write + 'static> fmt::Debug for Help<W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Help")
+            .field("registry", &self.registry.borrow().keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl<W: io::Write + 'static> Help<W> {
+    pub fn new(registry: Registry<W>) -> Self {
+        Help { registry }
+    }
+}
+
+impl<W: io::Write + 'static> CommandHandler<W> for Help<W> {
+    fn spec(&self) -> CommandSpec {
+        CommandSpec::new().optional_arg("command")
+    }
+
+    fn execute(&self, stdout: &mut W, args: ParsedArgs) -> usize {
+        let registry = self.registry.borrow();
+
+        match args.positional(0) {
+            Some(name) => match registry.get(name) {
+                Some(handler) => writeln!(stdout, "{}", handler.usage()).unwrap(),
+                None => writeln!(stdout, "No command {name}").unwrap(),
+            },
+            None => {
+                let mut names: Vec<&String> = registry.keys().collect();
+                names.sort();
+                let width = names.iter().map(|name| name.len()).max().unwrap_or(0);
+                for name in names {
+                    let handler = &registry[name];
+                    let displayed = handler.name_hint().unwrap_or(name);
+                    writeln!(
+                        stdout,
+                        "{:width$}  {}",
+                        displayed,
+                        handler.summary(),
+                        width = width
+                    )
+                    .unwrap();
+
+                    for (child, child_summary) in handler.subcommands() {
+                        writeln!(
+                            stdout,
+                            "{:width$}  {}",
+                            format!("{displayed} {child}"),
+                            child_summary,
+                            width = width
+                        )
+                        .unwrap();
+                    }
+                }
+            }
+        }
+        1
+    }
+
+    fn summary(&self) -> &str {
+        "List registered commands, or show one command's usage"
+    }
+
+    fn usage(&self) -> &str {
+        "help [command]"
+    }
+}
+
+/// A command that is itself a namespace of subcommands, like `git remote add`.
+/// Dispatches on the first argument token and forwards the rest to that
+/// subcommand's handler; an empty or unknown subcommand lists the ones
+/// available. Build it up with `add_cmd` and register the result under a
+/// single name with `Cmd::add_cmd`, same as any other handler:
+///
+/// ```rust,ignore
+/// let remote = handlers::Group::new()
+///     .add_cmd("add", Box::new(RemoteAdd::default()))
+///     .add_cmd("remove", Box::new(RemoteRemove::default()));
+/// cmd.add_cmd(String::from("remote"), Box::new(remote))?;
+/// ```
+#[derive(Default)]
+pub struct Group<W: io::Write + 'static> {
+    handlers: HashMap<String, Box<dyn CommandHandler<W>>>,
+}
+
+/// Hand-rolled instead of derived: `CommandHandler<W>: Debug` must hold for
+/// every `W: io::Write`, but a derive would add a spurious `W: Debug` bound.
+impl<W: io::Write + 'static> fmt::Debug for Group<W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Group")
+            .field("handlers", &self.subcommand_names())
+            .finish()
+    }
+}
+
+impl<W: io::Write + 'static> Group<W> {
+    pub fn new() -> Self {
+        Group {
+            handlers: HashMap::new(),
+        }
+    }
+
+    /// Register `handler` as the subcommand `name`.
+    pub fn add_cmd(mut self, name: &str, handler: Box<dyn CommandHandler<W>>) -> Self {
+        self.handlers.insert(name.to_string(), handler);
+        self
+    }
+
+    fn subcommand_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.handlers.keys().cloned().collect();
+        names.sort();
+        names
+    }
+
+    fn print_subcommands(&self, stdout: &mut W) {
+        writeln!(stdout, "Available subcommands:").unwrap();
+        for name in self.subcommand_names() {
+            writeln!(stdout, "  {name}").unwrap();
+        }
+    }
+}
+
+impl<W: io::Write + 'static> CommandHandler<W> for Group<W> {
+    fn spec(&self) -> CommandSpec {
+        CommandSpec::any()
+    }
+
+    fn execute(&self, stdout: &mut W, args: ParsedArgs) -> usize {
+        let (sub, rest) = match args.positionals().split_first() {
+            Some(parts) => parts,
+            None => {
+                self.print_subcommands(stdout);
+                return 1;
+            }
+        };
+
+        match self.handlers.get(sub) {
+            Some(handler) => match handler.spec().parse(rest.to_vec()) {
+                Ok(parsed) => handler.execute(stdout, parsed),
+                Err(e) => {
+                    writeln!(stdout, "{e}").unwrap();
+                    1
+                }
+            },
+            None => {
+                writeln!(stdout, "No subcommand {sub}").unwrap();
+                self.print_subcommands(stdout);
+                1
+            }
+        }
+    }
+
+    fn complete(&self, args: &[&str], word_being_typed: &str) -> Vec<String> {
+        match args.split_first() {
+            None => self
+                .subcommand_names()
+                .into_iter()
+                .filter(|name| name.starts_with(word_being_typed))
+                .collect(),
+            Some((sub, rest)) => match self.handlers.get(*sub) {
+                Some(handler) => handler.complete(rest, word_being_typed),
+                None => Vec::new(),
+            },
+        }
+    }
+
+    fn subcommands(&self) -> Vec<(String, String)> {
+        self.subcommand_names()
+            .into_iter()
+            .map(|name| {
+                let summary = self
+                    .handlers
+                    .get(&name)
+                    .map(|handler| handler.summary().to_string())
+                    .unwrap_or_default();
+                (name, summary)
+            })
+            .collect()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::command_spec::CommandSpec;
 
     #[test]
     fn test_quit(){
         let q = Quit::default();
-        assert_eq!(q.execute(&mut io::stdout(), "".to_string()), 0)
+        let args = CommandSpec::any().parse(Vec::new()).unwrap();
+        assert_eq!(q.execute(&mut io::stdout(), args), 0)
+    }
+
+    #[test]
+    fn test_history_prints_numbered_entries() {
+        let entries = Rc::new(RefCell::new(vec!["help".to_string(), "quit".to_string()]));
+        let history = History::new(entries);
+        let mut stdout = Vec::new();
+        let args = CommandSpec::any().parse(Vec::new()).unwrap();
+
+        history.execute(&mut stdout, args);
+
+        assert_eq!(String::from_utf8(stdout).unwrap(), "   1  help\n   2  quit\n");
+    }
+
+    fn registry_with_quit() -> Registry<Vec<u8>> {
+        let mut map: HashMap<String, Box<dyn CommandHandler<Vec<u8>>>> = HashMap::new();
+        map.insert(String::from("quit"), Box::new(Quit::default()));
+        Rc::new(RefCell::new(map))
+    }
+
+    #[test]
+    fn test_help_lists_registered_commands() {
+        let help = Help::new(registry_with_quit());
+        let mut stdout = Vec::new();
+        let args = help.spec().parse(Vec::new()).unwrap();
+
+        help.execute(&mut stdout, args);
+
+        assert_eq!(
+            String::from_utf8(stdout).unwrap(),
+            "quit  Exit the interpreter\n"
+        );
+    }
+
+    #[test]
+    fn test_help_shows_usage_for_one_command() {
+        let help = Help::new(registry_with_quit());
+        let mut stdout = Vec::new();
+        let args = help
+            .spec()
+            .parse(vec!["quit".to_string()])
+            .unwrap();
+
+        help.execute(&mut stdout, args);
+
+        assert_eq!(String::from_utf8(stdout).unwrap(), "quit\n");
+    }
+
+    #[test]
+    fn test_help_unknown_command_argument() {
+        let help = Help::new(registry_with_quit());
+        let mut stdout = Vec::new();
+        let args = help
+            .spec()
+            .parse(vec!["bogus".to_string()])
+            .unwrap();
+
+        help.execute(&mut stdout, args);
+
+        assert_eq!(String::from_utf8(stdout).unwrap(), "No command bogus\n");
+    }
+
+    fn remote_group() -> Group<Vec<u8>> {
+        Group::new()
+            .add_cmd("add", Box::new(Quit::default()))
+            .add_cmd("remove", Box::new(Quit::default()))
+    }
+
+    #[test]
+    fn test_group_dispatches_to_subcommand() {
+        let group = remote_group();
+        let mut stdout = Vec::new();
+        let args = group
+            .spec()
+            .parse(vec!["add".to_string(), "origin".to_string()])
+            .unwrap();
+
+        assert_eq!(group.execute(&mut stdout, args), 0);
+    }
+
+    #[test]
+    fn test_group_empty_args_lists_subcommands() {
+        let group = remote_group();
+        let mut stdout = Vec::new();
+        let args = group.spec().parse(Vec::new()).unwrap();
+
+        group.execute(&mut stdout, args);
+
+        assert_eq!(
+            String::from_utf8(stdout).unwrap(),
+            "Available subcommands:\n  add\n  remove\n"
+        );
+    }
+
+    #[test]
+    fn test_group_unknown_subcommand_lists_subcommands() {
+        let group = remote_group();
+        let mut stdout = Vec::new();
+        let args = group.spec().parse(vec!["bogus".to_string()]).unwrap();
+
+        group.execute(&mut stdout, args);
+
+        assert_eq!(
+            String::from_utf8(stdout).unwrap(),
+            "No subcommand bogus\nAvailable subcommands:\n  add\n  remove\n"
+        );
+    }
+
+    #[test]
+    fn test_group_complete_without_subcommand_typed() {
+        let group = remote_group();
+        assert_eq!(group.complete(&[], "a"), vec!["add".to_string()]);
+    }
+
+    #[test]
+    fn test_group_complete_delegates_to_subcommand() {
+        let group = remote_group();
+        assert_eq!(group.complete(&["add"], "origin"), Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_help_recurses_into_group_subcommands() {
+        let registry: Registry<Vec<u8>> = Rc::new(RefCell::new(HashMap::new()));
+        registry
+            .borrow_mut()
+            .insert(String::from("remote"), Box::new(remote_group()));
+
+        let help = Help::new(registry);
+        let mut stdout = Vec::new();
+        let args = help.spec().parse(Vec::new()).unwrap();
+
+        help.execute(&mut stdout, args);
+
+        assert_eq!(
+            String::from_utf8(stdout).unwrap(),
+            "remote  \nremote add  Exit the interpreter\nremote remove  Exit the interpreter\n"
+        );
     }
 }
\ No newline at end of file
@@ -13,6 +13,7 @@
 //! use std::io::Write;
 //!
 //! use cmd::command_handler::CommandHandler;
+//! use cmd::command_spec::{CommandSpec, ParsedArgs};
 //! use cmd::cmd::Cmd;
 //! use cmd::handlers::Quit;
 //!
@@ -21,8 +22,8 @@
 //! #[derive(Debug, Default)]
 //! pub struct Help;
 //!
-//! impl CommandHandler for Help {
-//!     fn execute(&self, _stdout: &mut io::Stdout, _args: String) -> usize {
+//! impl CommandHandler<io::Stdout> for Help {
+//!     fn execute(&self, _stdout: &mut io::Stdout, _args: ParsedArgs) -> usize {
 //!         writeln!(_stdout, "Help message").unwrap();
 //!         1
 //!     }
@@ -32,18 +33,17 @@
 //! #[derive(Debug, Default)]
 //! pub struct Touch;
 //!
-//! impl CommandHandler for Touch {
-//!     fn execute(&self, _stdout: &mut io::Stdout, _args: String) -> usize {
-//!         let filename = _args.split_whitespace().next().unwrap_or_default();
-//!
-//!         if filename.len() == 0 {
-//!             println!("Need to specify a filename");
-//!         } else {
-//!             let fs_result = std::fs::File::create(filename);
-//!             match fs_result {
-//!                 Ok(file) => println!("Created file: {:?}", file),
-//!                 Err(_) => println!("Could not create file: {}", filename)
-//!             }
+//! impl CommandHandler<io::Stdout> for Touch {
+//!     fn spec(&self) -> CommandSpec {
+//!         CommandSpec::new().arg("filename")
+//!     }
+//!
+//!     fn execute(&self, _stdout: &mut io::Stdout, _args: ParsedArgs) -> usize {
+//!         let filename = _args.positional(0).expect("spec requires filename");
+//!         let fs_result = std::fs::File::create(filename);
+//!         match fs_result {
+//!             Ok(file) => println!("Created file: {:?}", file),
+//!             Err(_) => println!("Could not create file: {}", filename)
 //!         }
 //!         1
 //!     }
@@ -51,7 +51,7 @@
 //!
 //!
 //! fn main() -> Result<(), std::io::Error>{
-//!     let mut cmd = Cmd::<io::BufReader<io::Stdin>, io::Stdout>::default();
+//!     let mut cmd = Cmd::<io::BufReader<io::Stdin>, io::Stdout>::stdio();
 //!
 //!     let help = Help::default();
 //!     let hello = Touch::default();
@@ -74,5 +74,14 @@ pub mod cmd;
 /// Contains the CommandHandler trait.
 pub mod command_handler;
 
+/// Contains the `CommandSpec` flag/argument schema builder and `ParsedArgs`.
+pub mod command_spec;
+
+/// Contains the `CmdError` type used by `Cmd::run_loader`'s batch dispatch.
+pub mod error;
+
 /// Contains common ready-to-use handlers
-pub mod handlers;
\ No newline at end of file
+pub mod handlers;
+
+/// Contains `Loader`, for reading a script of commands for `Cmd::run_loader`.
+pub mod loader;
\ No newline at end of file
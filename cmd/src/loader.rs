@@ -0,0 +1,97 @@
+use std::fs;
+use std::path::Path;
+
+use crate::error::CmdError;
+
+/// One line read by a `Loader`, after blank/comment filtering.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScriptLine {
+    /// 1-based line number in the original source, for `CmdError::UnknownCommand`.
+    pub line_no: usize,
+    /// The command text, with a leading `@` (if any) already stripped.
+    pub text: String,
+    /// If true, `Cmd::run_loader` ignores an error dispatching this line
+    /// instead of collecting it.
+    pub ignore_errors: bool,
+}
+
+/// Reads a batch of commands from a path or string for `Cmd::run_loader`,
+/// skipping blank lines and `#` comments. A line starting with `@` has its
+/// dispatch errors ignored, mirroring `make`'s "silence this line's
+/// failures" convention.
+#[derive(Debug, Default)]
+pub struct Loader {
+    lines: Vec<ScriptLine>,
+}
+
+impl Loader {
+    /// Parse `source` directly, with no filesystem access.
+    pub fn from_source(source: &str) -> Self {
+        let lines = source
+            .lines()
+            .enumerate()
+            .filter_map(|(i, raw)| {
+                let trimmed = raw.trim();
+                if trimmed.is_empty() || trimmed.starts_with('#') {
+                    return None;
+                }
+                let ignore_errors = trimmed.starts_with('@');
+                let text = if ignore_errors {
+                    trimmed[1..].trim_start().to_string()
+                } else {
+                    trimmed.to_string()
+                };
+                Some(ScriptLine {
+                    line_no: i + 1,
+                    text,
+                    ignore_errors,
+                })
+            })
+            .collect();
+        Loader { lines }
+    }
+
+    /// Read and parse the script at `path`.
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, CmdError> {
+        let source = fs::read_to_string(path)?;
+        Ok(Self::from_source(&source))
+    }
+
+    /// The filtered, numbered lines ready for `Cmd::run_loader`.
+    pub fn lines(&self) -> &[ScriptLine] {
+        &self.lines
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_source_skips_blank_lines_and_comments() {
+        let loader = Loader::from_source("greet world\n\n# a comment\nquit\n");
+        let lines: Vec<&str> = loader.lines().iter().map(|l| l.text.as_str()).collect();
+        assert_eq!(lines, vec!["greet world", "quit"]);
+    }
+
+    #[test]
+    fn test_from_source_tracks_original_line_numbers() {
+        let loader = Loader::from_source("greet world\n\n# a comment\nquit\n");
+        let line_nos: Vec<usize> = loader.lines().iter().map(|l| l.line_no).collect();
+        assert_eq!(line_nos, vec![1, 4]);
+    }
+
+    #[test]
+    fn test_from_source_leading_at_ignores_errors() {
+        let loader = Loader::from_source("@bogus arg\ngreet world");
+        assert_eq!(loader.lines()[0].text, "bogus arg");
+        assert!(loader.lines()[0].ignore_errors);
+        assert!(!loader.lines()[1].ignore_errors);
+    }
+
+    #[test]
+    fn test_from_path_missing_file_is_io_error() {
+        let err = Loader::from_path("does/not/exist.cmd").unwrap_err();
+        assert!(matches!(err, CmdError::Io(_)));
+    }
+}
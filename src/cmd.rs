@@ -1,7 +1,38 @@
 use std::collections::HashMap;
+use std::fmt;
 use std::io;
 
-use crate::command_handler::{CommandHandler, CommandResult};
+use crate::command_handler::{CommandHandler, CommandResult, FailurePolicy, TypedCommandHandler};
+
+/// Error returned when a line of input can't be split into arguments,
+/// e.g. because it contains an unterminated quote or a dangling escape.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseError(String);
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl ParseError {
+    pub(crate) fn new(message: impl Into<String>) -> Self {
+        ParseError(message.into())
+    }
+}
+
+/// A registered command: the boxed handler plus the help text extracted
+/// from it at registration time, so the built-in `help` command can look up
+/// a command's summary or full help text in O(1) without re-deriving it
+/// from `handler.help()` on every listing.
+struct Entry<W: io::Write> {
+    handler: Box<dyn CommandHandler<W>>,
+    summary: String,
+    help: String,
+    policy: FailurePolicy,
+}
 
 /// Command interpreter implemented as struct that contains
 /// boxed CommandHandlers in a hashmap with Strings as the keys
@@ -10,7 +41,7 @@ where
     W: io::Write + 'static,
     R: io::BufRead + 'static,
 {
-    handles: HashMap<String, Box<dyn CommandHandler<W>>>,
+    handles: HashMap<String, Entry<W>>,
     stdin: R,
     stdout: W,
 }
@@ -48,21 +79,116 @@ where
             self.stdin.read_line(&mut inputs)?;
             let inputs = inputs.trim();
 
-            // separate user input into a command and optional args
-            if !inputs.is_empty() {
-                let (command, args) = parse_cmd(inputs);
-                let args = split_args(args);
-
-                // attempt to execute command
-                if let Some(handler) = self.handles.get(command) {
-                    if matches!(
-                        handler.execute(&mut self.stdout, &args),
-                        CommandResult::Break
-                    ) {
-                        break;
+            if !inputs.is_empty() && self.dispatch_line(inputs)? {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    /// Execute every line read from `reader` through the same parse/dispatch
+    /// pipeline as the interactive `run` loop, without printing a prompt.
+    /// Blank lines and `#`-prefixed comment lines are skipped. Returns
+    /// `Ok(true)` if a handler returned `CommandResult::Break`, so that the
+    /// built-in `source` command (and any caller embedding a script inside a
+    /// larger session) can stop early too.
+    pub fn run_script<S: io::BufRead>(&mut self, reader: S) -> Result<bool, io::Error> {
+        for line in reader.lines() {
+            let line = line?;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if self.dispatch_line(line)? {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    /// Parse and dispatch a single non-empty `inputs` line: recognizes the
+    /// built-in `help`/`source` commands, otherwise looks up and executes a
+    /// registered handler, consulting its `FailurePolicy` on
+    /// `CommandResult::Error`. Returns `Ok(true)` if the loop driving this
+    /// (`run` or `run_script`) should stop.
+    fn dispatch_line(&mut self, inputs: &str) -> Result<bool, io::Error> {
+        let (command, args) = parse_cmd(inputs);
+        let args = match split_args(args) {
+            Ok(args) => args,
+            Err(e) => {
+                writeln!(self.stdout, "Parse error: {e}")?;
+                return Ok(false);
+            }
+        };
+        let args: Vec<&str> = args.iter().map(String::as_str).collect();
+
+        if command == "help" {
+            self.print_help(args.first().copied())?;
+            return Ok(false);
+        }
+
+        if command == "source" {
+            return self.dispatch_source(args.first().copied());
+        }
+
+        // attempt to execute command
+        if let Some(entry) = self.handles.get(command) {
+            match entry.handler.execute(&mut self.stdout, &args) {
+                CommandResult::Break => return Ok(true),
+                CommandResult::Continue => {}
+                CommandResult::Error(e) => match entry.policy {
+                    FailurePolicy::Ignore => {}
+                    FailurePolicy::Report => writeln!(self.stdout, "Error: {e}")?,
+                    FailurePolicy::Abort => {
+                        writeln!(self.stdout, "Error: {e}")?;
+                        return Err(e);
                     }
-                } else {
-                    writeln!(self.stdout, "No command {}", command)?;
+                },
+            }
+        } else {
+            writeln!(self.stdout, "No command {}", command)?;
+        }
+
+        Ok(false)
+    }
+
+    /// Built-in `source` command: opens `path` and feeds its lines through
+    /// `run_script`.
+    fn dispatch_source(&mut self, path: Option<&str>) -> Result<bool, io::Error> {
+        match path {
+            Some(path) => {
+                let file = std::fs::File::open(path)?;
+                self.run_script(io::BufReader::new(file))
+            }
+            None => {
+                writeln!(self.stdout, "usage: source <path>")?;
+                Ok(false)
+            }
+        }
+    }
+
+    /// Built-in `help` command: with no `name`, lists every registered
+    /// command and its one-line summary; with `name`, prints that command's
+    /// full help text.
+    fn print_help(&mut self, name: Option<&str>) -> Result<(), io::Error> {
+        match name {
+            Some(name) => match self.handles.get(name) {
+                Some(entry) if !entry.help.is_empty() => writeln!(self.stdout, "{}", entry.help)?,
+                Some(_) => writeln!(self.stdout, "No help available for {name}")?,
+                None => writeln!(self.stdout, "No command {name}")?,
+            },
+            None => {
+                let mut names: Vec<&String> = self.handles.keys().collect();
+                names.sort();
+                let width = names.iter().map(|name| name.len()).max().unwrap_or(0);
+                for name in names {
+                    writeln!(
+                        self.stdout,
+                        "{:width$}  {}",
+                        name,
+                        self.handles[name].summary,
+                        width = width
+                    )?;
                 }
             }
         }
@@ -82,44 +208,238 @@ where
 
     /// Insert new handler into the Cmd handles HashMap
     ///
+    /// Uses `FailurePolicy::default()` (`Report`) for `CommandResult::Error`;
+    /// use `add_cmd_with_policy` to choose a different policy.
+    ///
     /// ## Note: Will not overwrite existing handler names
     pub fn add_cmd(
         &mut self,
         name: String,
         handler: impl CommandHandler<W> + 'static,
     ) -> Result<(), io::Error> {
-        match self.handles.get(&name) {
-            Some(_) => write!(
+        self.add_cmd_with_policy(name, handler, FailurePolicy::default())
+    }
+
+    /// Insert new handler into the Cmd handles HashMap, with an explicit
+    /// `FailurePolicy` governing how `run` reacts to a `CommandResult::Error`
+    /// from this handler.
+    ///
+    /// ## Note: Will not overwrite existing handler names
+    pub fn add_cmd_with_policy(
+        &mut self,
+        name: String,
+        handler: impl CommandHandler<W> + 'static,
+        policy: FailurePolicy,
+    ) -> Result<(), io::Error> {
+        if self.handles.contains_key(&name) || is_builtin(&name) {
+            write!(
                 self.stdout,
                 "Warning: Command with handle {} already exists.",
                 name
-            )?,
-            None => {
-                self.handles.insert(name, Box::new(handler));
-            }
+            )?;
+        } else {
+            let help = handler.help().to_string();
+            let summary = help.lines().next().unwrap_or("").to_string();
+            self.handles.insert(
+                name,
+                Entry {
+                    handler: Box::new(handler),
+                    summary,
+                    help,
+                    policy,
+                },
+            );
         }
 
         Ok(())
     }
 
+    /// Insert a new handler declared with a typed `CommandSpec` instead of a
+    /// raw `&[&str]`. Raw tokens are validated against `handler.spec()`
+    /// before dispatch; on failure a usage error is printed to `self.stdout`
+    /// and `handler.execute` is not called.
+    ///
+    /// ## Note: Will not overwrite existing handler names
+    pub fn add_typed_cmd(
+        &mut self,
+        name: String,
+        handler: impl TypedCommandHandler<W> + 'static,
+    ) -> Result<(), io::Error> {
+        let help = handler.spec().help(&name);
+        let adapter = TypedAdapter {
+            name: name.clone(),
+            help,
+            handler,
+        };
+        self.add_cmd(name, adapter)
+    }
+
     #[cfg(test)]
     fn get_cmd(&self, key: String) -> Option<&Box<dyn CommandHandler<W>>> {
-        self.handles.get(&key)
+        self.handles.get(&key).map(|entry| &entry.handler)
+    }
+}
+
+impl<R> Cmd<R, Vec<u8>>
+where
+    R: io::BufRead + 'static,
+{
+    /// In-memory testing harness, in the spirit of `assert_cli`: feed
+    /// `input` through `run_script` and return everything written to the
+    /// output as a `String`. Lets downstream crates test their own
+    /// registered commands the same way, without hand-rolling a
+    /// `Cursor`/`Vec<u8>` reader and writer pair for every test:
+    ///
+    /// ```rust
+    /// use std::io;
+    /// use std::io::Write;
+    /// use rusty_cmd::cmd::Cmd;
+    /// use rusty_cmd::command_handler::CommandResult;
+    ///
+    /// let mut cmd = Cmd::new(io::Cursor::new(Vec::new()), Vec::new());
+    /// cmd.add_cmd_fn(String::from("greet"), |output, _args| {
+    ///     writeln!(output, "hello!").unwrap();
+    ///     CommandResult::Continue
+    /// }).unwrap();
+    ///
+    /// let output = cmd.run_captured("greet\n").unwrap();
+    /// assert_eq!(output, "hello!\n");
+    /// ```
+    pub fn run_captured(&mut self, input: &str) -> Result<String, io::Error> {
+        self.stdout.clear();
+        self.run_script(io::Cursor::new(input.as_bytes().to_vec()))?;
+        Ok(String::from_utf8_lossy(&self.stdout).into_owned())
+    }
+}
+
+/// Adapts a `TypedCommandHandler` into a plain `CommandHandler`, so typed
+/// commands can live in the same `handles` map as untyped ones: raw tokens
+/// are validated against `handler.spec()` and either forwarded as a `Matches`
+/// or rejected with a usage error written to the command's output.
+struct TypedAdapter<H> {
+    name: String,
+    help: String,
+    handler: H,
+}
+
+impl<W, H> CommandHandler<W> for TypedAdapter<H>
+where
+    W: io::Write,
+    H: TypedCommandHandler<W>,
+{
+    fn execute(&self, output: &mut W, args: &[&str]) -> CommandResult {
+        let spec = self.handler.spec();
+        match spec.parse(args) {
+            Ok(matches) => self.handler.execute(output, matches),
+            Err(e) => {
+                writeln!(output, "{e}").unwrap();
+                writeln!(output, "{}", spec.usage(&self.name)).unwrap();
+                CommandResult::Continue
+            }
+        }
+    }
+
+    fn help(&self) -> &str {
+        &self.help
     }
 }
 
+/// Whether `name` is a built-in command (`help`, `source`) handled directly
+/// by `dispatch_line` before the `handles` lookup. Registering a handler
+/// under either name would be silently unreachable, so `add_cmd_with_policy`
+/// rejects it the same way it rejects a duplicate name.
+fn is_builtin(name: &str) -> bool {
+    name == "help" || name == "source"
+}
+
 // Parse command string into command, and args Strings
 fn parse_cmd(line: &str) -> (&str, &str) {
     let line = line.trim();
-    let first_space = line.find(' ').unwrap_or(line.len());
-    let command = &line[..first_space];
+    let command_end = unquoted_whitespace(line).unwrap_or(line.len());
+    let command = &line[..command_end];
 
     let args = line[command.len()..].trim();
     (command, args)
 }
 
-fn split_args(args: &str) -> Vec<&str> {
-    args.split_whitespace().map(|arg| arg.trim()).collect()
+/// Byte offset of the first whitespace character in `line` that falls
+/// outside of a single- or double-quoted run, if any.
+fn unquoted_whitespace(line: &str) -> Option<usize> {
+    let mut in_single = false;
+    let mut in_double = false;
+    let mut escape = false;
+
+    for (i, c) in line.char_indices() {
+        if escape {
+            escape = false;
+            continue;
+        }
+        match c {
+            '\\' if !in_single => escape = true,
+            '\'' if !in_double => in_single = !in_single,
+            '"' if !in_single => in_double = !in_double,
+            c if c.is_whitespace() && !in_single && !in_double => return Some(i),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Split `args` into resolved argument tokens the way a POSIX shell would:
+/// unquoted whitespace separates tokens, single quotes take everything
+/// literally, double quotes allow only `\"` and `\\` escapes (any other
+/// backslash is kept as-is), and an unquoted backslash escapes the next
+/// character. Returns a `ParseError` if
+/// a quote or escape is left unterminated.
+fn split_args(args: &str) -> Result<Vec<String>, ParseError> {
+    let mut tokens = Vec::new();
+    let mut token = String::new();
+    let mut in_single = false;
+    let mut in_double = false;
+    let mut escape = false;
+    let mut quoted = false;
+
+    for c in args.chars() {
+        if escape {
+            if in_double && c != '"' && c != '\\' {
+                token.push('\\');
+            }
+            token.push(c);
+            escape = false;
+            continue;
+        }
+
+        match c {
+            '\\' if !in_single => escape = true,
+            '\'' if !in_double => {
+                in_single = !in_single;
+                quoted = true;
+            }
+            '"' if !in_single => {
+                in_double = !in_double;
+                quoted = true;
+            }
+            c if c.is_whitespace() && !in_single && !in_double => {
+                if !token.is_empty() || quoted {
+                    tokens.push(std::mem::take(&mut token));
+                    quoted = false;
+                }
+            }
+            c => token.push(c),
+        }
+    }
+
+    if escape {
+        return Err(ParseError("dangling escape".to_string()));
+    }
+    if in_single || in_double {
+        return Err(ParseError("unterminated quote".to_string()));
+    }
+    if !token.is_empty() || quoted {
+        tokens.push(token);
+    }
+
+    Ok(tokens)
 }
 
 #[cfg(test)]
@@ -128,7 +448,8 @@ mod tests {
     use std::io::{self, BufReader, Write};
 
     use super::*;
-    use crate::command_handler::CommandResult;
+    use crate::command_handler::{CommandResult, FailurePolicy, TypedCommandHandler};
+    use crate::command_spec::{CommandSpec, Matches};
     use crate::handlers::Quit;
 
     #[derive(Default)]
@@ -214,6 +535,21 @@ mod tests {
         assert_eq!(line1, "Warning: Command with handle greet already exists.");
     }
 
+    #[test]
+    fn test_add_cmd_rejects_builtin_names() {
+        let mut app = Cmd::new(io::Cursor::new(Vec::new()), Vec::new());
+
+        app.add_cmd(String::from("help"), Greeting::default()).unwrap();
+        app.add_cmd(String::from("source"), Greeting::default()).unwrap();
+
+        let output = String::from_utf8(app.stdout).unwrap();
+        assert_eq!(
+            output,
+            "Warning: Command with handle help already exists.\
+             Warning: Command with handle source already exists."
+        );
+    }
+
     #[test]
     fn test_add_cmd_always_error() {
         let f = std::fs::File::open("test_files/test_in.txt").unwrap();
@@ -314,14 +650,273 @@ mod tests {
     #[test]
     fn test_split_args() {
         let args = "arg1 arg2 arg3";
-        let expected = vec!["arg1", "arg2", "arg3"];
-        assert_eq!(split_args(args), expected);
+        let expected = vec!["arg1".to_string(), "arg2".to_string(), "arg3".to_string()];
+        assert_eq!(split_args(args).unwrap(), expected);
     }
 
     #[test]
     fn split_empty_args() {
         let args = "";
-        let expected: Vec<&str> = vec![];
-        assert_eq!(split_args(args), expected);
+        let expected: Vec<String> = vec![];
+        assert_eq!(split_args(args).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_split_args_quoted_arg_with_space() {
+        let args = r#""my file.txt""#;
+        assert_eq!(split_args(args).unwrap(), vec!["my file.txt".to_string()]);
+    }
+
+    #[test]
+    fn test_split_args_single_quotes_are_literal() {
+        let args = r#"'a\b'"#;
+        assert_eq!(split_args(args).unwrap(), vec![r"a\b".to_string()]);
+    }
+
+    #[test]
+    fn test_split_args_double_quote_escapes() {
+        let args = r#""a\"b""#;
+        assert_eq!(split_args(args).unwrap(), vec![r#"a"b"#.to_string()]);
+    }
+
+    #[test]
+    fn test_split_args_unterminated_quote_is_error() {
+        assert_eq!(
+            split_args(r#""unterminated"#).unwrap_err().to_string(),
+            "unterminated quote"
+        );
+    }
+
+    #[test]
+    fn test_split_args_dangling_escape_is_error() {
+        assert_eq!(split_args(r"\").unwrap_err().to_string(), "dangling escape");
+    }
+
+    #[test]
+    fn test_parse_cmd_splits_on_unquoted_whitespace_only() {
+        let line = r#"greet "Hello there""#;
+        assert_eq!(parse_cmd(line), ("greet", r#""Hello there""#));
+    }
+
+    #[derive(Default)]
+    struct Echo;
+
+    impl<W: io::Write> TypedCommandHandler<W> for Echo {
+        fn spec(&self) -> CommandSpec {
+            CommandSpec::new().arg("word", "word to echo")
+        }
+
+        fn execute(&self, output: &mut W, args: Matches) -> CommandResult {
+            write!(output, "{}", args.arg("word").expect("spec requires word")).unwrap();
+            CommandResult::Continue
+        }
+    }
+
+    #[test]
+    fn test_add_typed_cmd_dispatches_to_handler_on_valid_args() {
+        let f = std::fs::File::open("test_files/test_in.txt").unwrap();
+        let stdin = io::BufReader::new(f);
+        let mut app = Cmd::new(stdin, Vec::new());
+        app.add_typed_cmd(String::from("echo"), Echo).unwrap();
+
+        let handler = app.get_cmd(String::from("echo")).unwrap();
+        let mut stdout = vec![];
+        handler.execute(&mut stdout, &["hi"]);
+
+        assert_eq!(String::from_utf8(stdout).unwrap(), "hi");
+    }
+
+    #[test]
+    fn test_add_typed_cmd_prints_usage_error_instead_of_dispatching() {
+        let f = std::fs::File::open("test_files/test_in.txt").unwrap();
+        let stdin = io::BufReader::new(f);
+        let mut app = Cmd::new(stdin, Vec::new());
+        app.add_typed_cmd(String::from("echo"), Echo).unwrap();
+
+        let handler = app.get_cmd(String::from("echo")).unwrap();
+        let mut stdout = vec![];
+        handler.execute(&mut stdout, &[]);
+
+        assert_eq!(
+            String::from_utf8(stdout).unwrap(),
+            "Missing required argument word\nusage: echo <word>\n"
+        );
+    }
+
+    #[test]
+    fn test_run_help_lists_registered_commands_with_summaries() {
+        let stdin = io::BufReader::new("help\nquit\n".as_bytes());
+        let mut app = Cmd::new(stdin, Vec::new());
+        app.add_cmd(String::from("greet"), Greeting::default()).unwrap();
+        app.add_cmd(String::from("quit"), Quit::default()).unwrap();
+
+        app.run().unwrap();
+
+        let output = String::from_utf8(app.stdout).unwrap();
+        assert!(output.contains("quit   quit: Exit the interpreter\n"));
+    }
+
+    #[test]
+    fn test_run_help_with_name_shows_full_help_text() {
+        let stdin = io::BufReader::new("help quit\nquit\n".as_bytes());
+        let mut app = Cmd::new(stdin, Vec::new());
+        app.add_cmd(String::from("quit"), Quit::default()).unwrap();
+
+        app.run().unwrap();
+
+        let output = String::from_utf8(app.stdout).unwrap();
+        assert!(output.contains("quit: Exit the interpreter\n"));
+    }
+
+    #[test]
+    fn test_run_help_with_name_no_help_text() {
+        let stdin = io::BufReader::new("help greet\nquit\n".as_bytes());
+        let mut app = Cmd::new(stdin, Vec::new());
+        app.add_cmd(String::from("greet"), Greeting::default()).unwrap();
+        app.add_cmd(String::from("quit"), Quit::default()).unwrap();
+
+        app.run().unwrap();
+
+        let output = String::from_utf8(app.stdout).unwrap();
+        assert!(output.contains("No help available for greet\n"));
+    }
+
+    #[test]
+    fn test_run_help_with_unknown_name() {
+        let stdin = io::BufReader::new("help bogus\nquit\n".as_bytes());
+        let mut app = Cmd::new(stdin, Vec::new());
+        app.add_cmd(String::from("quit"), Quit::default()).unwrap();
+
+        app.run().unwrap();
+
+        let output = String::from_utf8(app.stdout).unwrap();
+        assert!(output.contains("No command bogus\n"));
+    }
+
+    #[derive(Default)]
+    struct Failing;
+
+    impl<W: io::Write> CommandHandler<W> for Failing {
+        fn execute(&self, _output: &mut W, _args: &[&str]) -> CommandResult {
+            CommandResult::Error(io::Error::new(io::ErrorKind::Other, "boom"))
+        }
+    }
+
+    #[test]
+    fn test_run_report_policy_prints_error_and_continues() {
+        let stdin = io::BufReader::new("fail\nquit\n".as_bytes());
+        let mut app = Cmd::new(stdin, Vec::new());
+        app.add_cmd_with_policy(String::from("fail"), Failing, FailurePolicy::Report)
+            .unwrap();
+        app.add_cmd(String::from("quit"), Quit::default()).unwrap();
+
+        app.run().unwrap();
+
+        let output = String::from_utf8(app.stdout).unwrap();
+        assert!(output.contains("Error: boom\n"));
+    }
+
+    #[test]
+    fn test_run_ignore_policy_swallows_error() {
+        let stdin = io::BufReader::new("fail\nquit\n".as_bytes());
+        let mut app = Cmd::new(stdin, Vec::new());
+        app.add_cmd_with_policy(String::from("fail"), Failing, FailurePolicy::Ignore)
+            .unwrap();
+        app.add_cmd(String::from("quit"), Quit::default()).unwrap();
+
+        app.run().unwrap();
+
+        let output = String::from_utf8(app.stdout).unwrap();
+        assert!(!output.contains("boom"));
+    }
+
+    #[test]
+    fn test_run_abort_policy_stops_loop_and_returns_error() {
+        let stdin = io::BufReader::new("fail\nquit\n".as_bytes());
+        let mut app = Cmd::new(stdin, Vec::new());
+        app.add_cmd_with_policy(String::from("fail"), Failing, FailurePolicy::Abort)
+            .unwrap();
+        app.add_cmd(String::from("quit"), Quit::default()).unwrap();
+
+        let e = app.run().unwrap_err();
+
+        assert_eq!(e.to_string(), "boom");
+    }
+
+    #[test]
+    fn test_run_script_skips_blank_and_comment_lines() {
+        let mut app = Cmd::new(io::BufReader::new(&b""[..]), Vec::new());
+        app.add_cmd(String::from("greet"), Greeting::default()).unwrap();
+
+        let script = "\n# comment\ngreet\n";
+        let stopped = app.run_script(io::BufReader::new(script.as_bytes())).unwrap();
+
+        assert!(!stopped);
+        assert_eq!(String::from_utf8(app.stdout).unwrap(), "Hello there!");
+    }
+
+    #[test]
+    fn test_run_script_stops_on_break() {
+        let mut app = Cmd::new(io::BufReader::new(&b""[..]), Vec::new());
+        app.add_cmd(String::from("greet"), Greeting::default()).unwrap();
+        app.add_cmd(String::from("quit"), Quit::default()).unwrap();
+
+        let script = "greet\nquit\ngreet\n";
+        let stopped = app.run_script(io::BufReader::new(script.as_bytes())).unwrap();
+
+        assert!(stopped);
+        assert_eq!(String::from_utf8(app.stdout).unwrap(), "Hello there!");
+    }
+
+    #[test]
+    fn test_source_command_runs_script_file() {
+        let path = std::env::temp_dir().join(format!(
+            "rusty_cmd_test_source_{}.txt",
+            std::process::id()
+        ));
+        std::fs::write(&path, "greet\n").unwrap();
+
+        let input = format!("source {}\nquit\n", path.display());
+        let mut app = Cmd::new(io::Cursor::new(input.into_bytes()), Vec::new());
+        app.add_cmd(String::from("greet"), Greeting::default()).unwrap();
+        app.add_cmd(String::from("quit"), Quit::default()).unwrap();
+
+        app.run().unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let output = String::from_utf8(app.stdout).unwrap();
+        assert!(output.contains("Hello there!"));
+    }
+
+    #[test]
+    fn test_source_missing_path_prints_usage() {
+        let mut app = Cmd::new(io::Cursor::new(b"source\nquit\n".to_vec()), Vec::new());
+        app.add_cmd(String::from("quit"), Quit::default()).unwrap();
+
+        app.run().unwrap();
+
+        let output = String::from_utf8(app.stdout).unwrap();
+        assert!(output.contains("usage: source <path>\n"));
+    }
+
+    #[test]
+    fn test_run_captured_returns_output_as_string() {
+        let mut app = Cmd::new(io::Cursor::new(Vec::new()), Vec::new());
+        app.add_cmd(String::from("greet"), Greeting::default()).unwrap();
+
+        let output = app.run_captured("greet\n").unwrap();
+
+        assert_eq!(output, "Hello there!");
+    }
+
+    #[test]
+    fn test_run_captured_resets_output_between_calls() {
+        let mut app = Cmd::new(io::Cursor::new(Vec::new()), Vec::new());
+        app.add_cmd(String::from("greet"), Greeting::default()).unwrap();
+
+        app.run_captured("greet\n").unwrap();
+        let output = app.run_captured("greet\n").unwrap();
+
+        assert_eq!(output, "Hello there!");
     }
 }
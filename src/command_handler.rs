@@ -43,12 +43,39 @@ where
 {
     /// Required method to execute a command
     fn execute(&self, output: &mut W, args: &[&str]) -> CommandResult;
+
+    /// Help text for this command, shown by the built-in `help` command.
+    /// The first line is used as the one-line summary in `help`'s listing
+    /// of every registered command; `help <name>` prints the whole string.
+    ///
+    /// Defaults to an empty string, meaning the command has no help text: it
+    /// is still listed by `help`, with an empty summary.
+    fn help(&self) -> &str {
+        ""
+    }
 }
 
-/// Enum to determine whether to continue or break the Cmd.run() loop
+/// Enum to determine whether to continue or break the Cmd.run() loop, or
+/// report that the command failed
 pub enum CommandResult {
     Continue,
     Break,
+    /// The command failed; how `Cmd::run` reacts is governed by the
+    /// command's `FailurePolicy`.
+    Error(std::io::Error),
+}
+
+/// How `Cmd::run` should react when a handler returns `CommandResult::Error`.
+/// Set per-command with `Cmd::add_cmd_with_policy`; defaults to `Report`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FailurePolicy {
+    /// Swallow the error and continue the loop.
+    Ignore,
+    /// Print the error to the command's output and continue the loop.
+    #[default]
+    Report,
+    /// Print the error and stop the loop, returning the error from `run`.
+    Abort,
 }
 
 /// Blanket CommandHandler implementation for Fn(&mut W, &[&str]) -> CommandResult
@@ -62,3 +89,49 @@ where
         self(output, args)
     }
 }
+
+/// Interface for commands declared with a typed `CommandSpec` instead of a
+/// raw `&[&str]`. Registered with `Cmd::add_typed_cmd`, which validates the
+/// tokenized input against `spec()` before dispatch: on success `execute`
+/// receives the parsed `Matches`, and on failure a usage error is printed to
+/// the command's output without calling `execute` at all.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::io;
+/// use rusty_cmd::command_handler::{CommandResult, TypedCommandHandler};
+/// use rusty_cmd::command_spec::{CommandSpec, Matches};
+///
+/// /// CommandHandler that emulates the basic bash touch command to create a new file
+/// #[derive(Default)]
+/// pub struct Touch;
+///
+/// impl<W: io::Write> TypedCommandHandler<W> for Touch {
+///     fn spec(&self) -> CommandSpec {
+///         CommandSpec::new()
+///             .summary("Create a new, empty file")
+///             .arg("filename", "path of the file to create")
+///     }
+///
+///     fn execute(&self, output: &mut W, args: Matches) -> CommandResult {
+///         let filename = args.arg("filename").expect("spec requires filename");
+///         match std::fs::File::create(filename) {
+///             Ok(file) => writeln!(output, "Created file: {:?}", file).unwrap(),
+///             Err(_) => writeln!(output, "Could not create file: {}", filename).unwrap(),
+///         }
+///         CommandResult::Continue
+///     }
+/// }
+/// ```
+pub trait TypedCommandHandler<W>
+where
+    W: std::io::Write,
+{
+    /// Declarative schema of this command's positional arguments and flags.
+    fn spec(&self) -> crate::command_spec::CommandSpec;
+
+    /// Required method to execute a command once its tokens have been
+    /// validated against `spec()`.
+    fn execute(&self, output: &mut W, args: crate::command_spec::Matches) -> CommandResult;
+}
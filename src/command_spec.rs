@@ -0,0 +1,353 @@
+use std::collections::HashMap;
+
+use crate::cmd::ParseError;
+
+/// How many times a positional argument may appear.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Arity {
+    Required,
+    Optional,
+    Repeated,
+}
+
+#[derive(Debug, Clone)]
+struct PositionalSpec {
+    name: String,
+    description: String,
+    arity: Arity,
+}
+
+#[derive(Debug, Clone)]
+struct FlagSpec {
+    name: String,
+    short: Option<char>,
+    description: String,
+    takes_value: bool,
+}
+
+/// Declarative schema of the positional arguments and flags a typed command
+/// expects, inspired by the xflags code generator. `CommandSpec::parse`
+/// validates raw tokens against the schema and produces a `Matches`; its
+/// `summary` and per-arg/flag descriptions drive an auto-generated
+/// `usage: <name> ...` string.
+#[derive(Debug, Clone, Default)]
+pub struct CommandSpec {
+    summary: String,
+    positionals: Vec<PositionalSpec>,
+    flags: Vec<FlagSpec>,
+}
+
+impl CommandSpec {
+    /// Create an empty spec with no positional args or flags.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// One-line description of the command, shown in the auto-generated help text.
+    pub fn summary(mut self, summary: &str) -> Self {
+        self.summary = summary.to_string();
+        self
+    }
+
+    /// Add a required positional argument, rejecting the command if it's missing.
+    pub fn arg(mut self, name: &str, description: &str) -> Self {
+        self.positionals.push(PositionalSpec {
+            name: name.to_string(),
+            description: description.to_string(),
+            arity: Arity::Required,
+        });
+        self
+    }
+
+    /// Add a positional argument that may be omitted.
+    pub fn optional_arg(mut self, name: &str, description: &str) -> Self {
+        self.positionals.push(PositionalSpec {
+            name: name.to_string(),
+            description: description.to_string(),
+            arity: Arity::Optional,
+        });
+        self
+    }
+
+    /// Add a positional argument that consumes every remaining token. Only
+    /// meaningful as the last positional in the spec.
+    pub fn repeated_arg(mut self, name: &str, description: &str) -> Self {
+        self.positionals.push(PositionalSpec {
+            name: name.to_string(),
+            description: description.to_string(),
+            arity: Arity::Repeated,
+        });
+        self
+    }
+
+    /// Add a boolean flag, matched as `--name` or `-short`.
+    pub fn flag(mut self, name: &str, short: char, description: &str) -> Self {
+        self.flags.push(FlagSpec {
+            name: name.to_string(),
+            short: Some(short),
+            description: description.to_string(),
+            takes_value: false,
+        });
+        self
+    }
+
+    /// Add a flag that takes the token following it as its value, matched as
+    /// `--name value` or `-short value`.
+    pub fn value_flag(mut self, name: &str, short: char, description: &str) -> Self {
+        self.flags.push(FlagSpec {
+            name: name.to_string(),
+            short: Some(short),
+            description: description.to_string(),
+            takes_value: true,
+        });
+        self
+    }
+
+    fn find_flag_long(&self, name: &str) -> Option<&FlagSpec> {
+        self.flags.iter().find(|flag| flag.name == name)
+    }
+
+    fn find_flag_short(&self, short: char) -> Option<&FlagSpec> {
+        self.flags.iter().find(|flag| flag.short == Some(short))
+    }
+
+    /// Render a one-line `usage: <command_name> ...` string from this
+    /// schema's flags and positionals.
+    pub fn usage(&self, command_name: &str) -> String {
+        let mut usage = format!("usage: {command_name}");
+
+        for flag in &self.flags {
+            if flag.takes_value {
+                usage.push_str(&format!(" [--{} <value>]", flag.name));
+            } else {
+                usage.push_str(&format!(" [--{}]", flag.name));
+            }
+        }
+
+        for positional in &self.positionals {
+            match positional.arity {
+                Arity::Required => usage.push_str(&format!(" <{}>", positional.name)),
+                Arity::Optional => usage.push_str(&format!(" [{}]", positional.name)),
+                Arity::Repeated => usage.push_str(&format!(" <{}>...", positional.name)),
+            }
+        }
+
+        usage
+    }
+
+    /// Render the full help text for this command: `summary`, the
+    /// `usage()` line, then each positional/flag's own description.
+    pub fn help(&self, command_name: &str) -> String {
+        let mut help = String::new();
+
+        if !self.summary.is_empty() {
+            help.push_str(&self.summary);
+            help.push('\n');
+        }
+        help.push_str(&self.usage(command_name));
+
+        for positional in &self.positionals {
+            if !positional.description.is_empty() {
+                help.push_str(&format!("\n  {}  {}", positional.name, positional.description));
+            }
+        }
+        for flag in &self.flags {
+            if !flag.description.is_empty() {
+                help.push_str(&format!("\n  --{}  {}", flag.name, flag.description));
+            }
+        }
+
+        help
+    }
+
+    /// Validate `tokens` against this schema, producing a `Matches`. Returns
+    /// a `ParseError` describing the first unknown flag, missing flag value,
+    /// or missing required argument encountered.
+    pub fn parse(&self, tokens: &[&str]) -> Result<Matches, ParseError> {
+        let mut positional_tokens = Vec::new();
+        let mut flags = HashMap::new();
+        let mut values: HashMap<String, Vec<String>> = HashMap::new();
+
+        let mut tokens = tokens.iter();
+        while let Some(&token) = tokens.next() {
+            let spec = if let Some(name) = token.strip_prefix("--") {
+                self.find_flag_long(name)
+                    .ok_or_else(|| ParseError::new(format!("Unknown flag --{name}")))?
+            } else if let Some(short) = token.strip_prefix('-').filter(|s| s.len() == 1) {
+                let short = short.chars().next().expect("checked len == 1 above");
+                self.find_flag_short(short)
+                    .ok_or_else(|| ParseError::new(format!("Unknown flag -{short}")))?
+            } else {
+                positional_tokens.push(token);
+                continue;
+            };
+
+            if spec.takes_value {
+                let value = tokens
+                    .next()
+                    .ok_or_else(|| ParseError::new(format!("Missing value for --{}", spec.name)))?;
+                values.entry(spec.name.clone()).or_default().push(value.to_string());
+            } else {
+                flags.insert(spec.name.clone(), true);
+            }
+        }
+
+        let mut positional_tokens = positional_tokens.into_iter();
+        let mut positionals = HashMap::new();
+        let mut repeated = HashMap::new();
+
+        for spec in &self.positionals {
+            match spec.arity {
+                Arity::Required => {
+                    let value = positional_tokens.next().ok_or_else(|| {
+                        ParseError::new(format!("Missing required argument {}", spec.name))
+                    })?;
+                    positionals.insert(spec.name.clone(), value.to_string());
+                }
+                Arity::Optional => {
+                    if let Some(value) = positional_tokens.next() {
+                        positionals.insert(spec.name.clone(), value.to_string());
+                    }
+                }
+                Arity::Repeated => {
+                    let rest: Vec<String> = positional_tokens.by_ref().map(str::to_string).collect();
+                    repeated.insert(spec.name.clone(), rest);
+                }
+            }
+        }
+
+        Ok(Matches {
+            positionals,
+            repeated,
+            flags,
+            values,
+        })
+    }
+}
+
+/// Parsed result of validating raw tokens against a `CommandSpec`, passed to
+/// a `TypedCommandHandler::execute` in place of a raw `&[&str]`.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Matches {
+    positionals: HashMap<String, String>,
+    repeated: HashMap<String, Vec<String>>,
+    flags: HashMap<String, bool>,
+    values: HashMap<String, Vec<String>>,
+}
+
+impl Matches {
+    /// The value of a required or optional positional argument named `name`.
+    pub fn arg(&self, name: &str) -> Option<&str> {
+        self.positionals.get(name).map(String::as_str)
+    }
+
+    /// Whether the boolean flag named `name` was present.
+    pub fn flag(&self, name: &str) -> bool {
+        *self.flags.get(name).unwrap_or(&false)
+    }
+
+    /// The values collected for a repeated positional argument, or a
+    /// value-taking flag named `name` (possibly more than one, if the flag
+    /// was passed more than once). Empty if `name` is unknown or wasn't matched.
+    pub fn values(&self, name: &str) -> &[String] {
+        self.repeated
+            .get(name)
+            .or_else(|| self.values.get(name))
+            .map(Vec::as_slice)
+            .unwrap_or(&[])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_required_arg() {
+        let spec = CommandSpec::new().arg("filename", "file to create");
+        let matches = spec.parse(&["foo.txt"]).unwrap();
+        assert_eq!(matches.arg("filename"), Some("foo.txt"));
+    }
+
+    #[test]
+    fn test_parse_missing_required_arg_is_error() {
+        let spec = CommandSpec::new().arg("filename", "file to create");
+        assert_eq!(
+            spec.parse(&[]).unwrap_err().to_string(),
+            "Missing required argument filename"
+        );
+    }
+
+    #[test]
+    fn test_parse_optional_arg_may_be_omitted() {
+        let spec = CommandSpec::new().optional_arg("filter", "optional filter");
+        let matches = spec.parse(&[]).unwrap();
+        assert_eq!(matches.arg("filter"), None);
+    }
+
+    #[test]
+    fn test_parse_repeated_arg_collects_remaining_tokens() {
+        let spec = CommandSpec::new().repeated_arg("files", "files to remove");
+        let matches = spec.parse(&["a.txt", "b.txt", "c.txt"]).unwrap();
+        assert_eq!(matches.values("files"), &["a.txt", "b.txt", "c.txt"]);
+    }
+
+    #[test]
+    fn test_parse_boolean_flag_long_and_short() {
+        let spec = CommandSpec::new().flag("force", 'f', "skip confirmation");
+
+        assert!(spec.parse(&["--force"]).unwrap().flag("force"));
+        assert!(spec.parse(&["-f"]).unwrap().flag("force"));
+        assert!(!spec.parse(&[]).unwrap().flag("force"));
+    }
+
+    #[test]
+    fn test_parse_value_flag() {
+        let spec = CommandSpec::new().value_flag("output", 'o', "output path");
+        let matches = spec.parse(&["--output", "out.txt"]).unwrap();
+        assert_eq!(matches.values("output"), &["out.txt"]);
+    }
+
+    #[test]
+    fn test_parse_value_flag_missing_value_is_error() {
+        let spec = CommandSpec::new().value_flag("output", 'o', "output path");
+        assert_eq!(
+            spec.parse(&["--output"]).unwrap_err().to_string(),
+            "Missing value for --output"
+        );
+    }
+
+    #[test]
+    fn test_parse_unknown_flag_is_error() {
+        let spec = CommandSpec::new();
+        assert_eq!(
+            spec.parse(&["--bogus"]).unwrap_err().to_string(),
+            "Unknown flag --bogus"
+        );
+    }
+
+    #[test]
+    fn test_usage_renders_flags_and_positionals() {
+        let spec = CommandSpec::new()
+            .flag("force", 'f', "skip confirmation")
+            .arg("filename", "file to remove")
+            .optional_arg("backup", "backup path");
+
+        assert_eq!(
+            spec.usage("rm"),
+            "usage: rm [--force] <filename> [backup]"
+        );
+    }
+
+    #[test]
+    fn test_help_includes_summary_and_descriptions() {
+        let spec = CommandSpec::new()
+            .summary("Remove a file")
+            .arg("filename", "file to remove");
+
+        assert_eq!(
+            spec.help("rm"),
+            "Remove a file\nusage: rm <filename>\n  filename  file to remove"
+        );
+    }
+}
@@ -1,5 +1,6 @@
 use crate::command_handler::{CommandHandler, CommandResult};
 use std::io;
+use std::process;
 
 /// Ready-to-use command to quit the cmd loop
 ///
@@ -8,9 +9,82 @@ use std::io;
 pub struct Quit {}
 
 impl<W: io::Write> CommandHandler<W> for Quit {
-    fn execute(&self, _cmd: &mut W, _args: &str) -> CommandResult {
+    fn execute(&self, _cmd: &mut W, _args: &[&str]) -> CommandResult {
         CommandResult::Break
     }
+
+    fn help(&self) -> &str {
+        "quit: Exit the interpreter"
+    }
+}
+
+/// Ready-to-use command that runs an external program via
+/// `std::process::Command`, following the Fuchsia test-runner pattern of
+/// starting from a cleared environment and only passing through variables
+/// that are explicitly whitelisted, rather than inheriting the whole parent
+/// environment. REPL args are forwarded as process args, and the child's
+/// stdout/stderr are written into the same `W` the rest of the interpreter
+/// writes to:
+///
+/// ```rust,ignore
+/// cmd.add_cmd(
+///     String::from("git"),
+///     handlers::Exec::new("/usr/bin/git").pass_env("HOME"),
+/// )?;
+/// ```
+#[derive(Debug, Default)]
+pub struct Exec {
+    program: String,
+    env: Vec<(String, String)>,
+}
+
+impl Exec {
+    /// `program` is the path of the executable to run. Its child process
+    /// starts with a cleared environment; use `env`/`pass_env` to whitelist
+    /// the variables it should see.
+    pub fn new(program: &str) -> Self {
+        Exec {
+            program: program.to_string(),
+            env: Vec::new(),
+        }
+    }
+
+    /// Set `name` to a fixed `value` in the child's environment.
+    pub fn env(mut self, name: &str, value: &str) -> Self {
+        self.env.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    /// Pass `name` through to the child's environment with the value it has
+    /// in this process's own environment, if set.
+    pub fn pass_env(mut self, name: &str) -> Self {
+        if let Ok(value) = std::env::var(name) {
+            self.env.push((name.to_string(), value));
+        }
+        self
+    }
+}
+
+impl<W: io::Write> CommandHandler<W> for Exec {
+    fn execute(&self, output: &mut W, args: &[&str]) -> CommandResult {
+        let mut command = process::Command::new(&self.program);
+        command.args(args).env_clear().envs(self.env.iter().cloned());
+
+        match command.output() {
+            Ok(result) => {
+                output.write_all(&result.stdout).unwrap();
+                output.write_all(&result.stderr).unwrap();
+            }
+            Err(e) => {
+                writeln!(output, "Failed to run {}: {e}", self.program).unwrap();
+            }
+        }
+        CommandResult::Continue
+    }
+
+    fn help(&self) -> &str {
+        "Run an external program with a cleared, whitelisted environment"
+    }
 }
 
 #[cfg(test)]
@@ -21,8 +95,44 @@ mod tests {
     fn test_quit() {
         let q = Quit::default();
         assert!(matches!(
-            q.execute(&mut io::stdout(), ""),
+            q.execute(&mut io::stdout(), &[]),
             CommandResult::Break
         ))
     }
+
+    #[test]
+    fn test_exec_forwards_args_and_captures_stdout() {
+        let exec = Exec::new("/bin/echo");
+        let mut stdout = Vec::new();
+
+        exec.execute(&mut stdout, &["hello"]);
+
+        assert_eq!(String::from_utf8(stdout).unwrap(), "hello\n");
+    }
+
+    #[test]
+    fn test_exec_clears_environment_except_whitelisted_vars() {
+        std::env::set_var("RUSTY_CMD_TEST_VAR", "visible");
+        let exec = Exec::new("/usr/bin/env").pass_env("RUSTY_CMD_TEST_VAR");
+        let mut stdout = Vec::new();
+
+        exec.execute(&mut stdout, &[]);
+
+        assert_eq!(
+            String::from_utf8(stdout).unwrap(),
+            "RUSTY_CMD_TEST_VAR=visible\n"
+        );
+    }
+
+    #[test]
+    fn test_exec_reports_spawn_failure() {
+        let exec = Exec::new("/no/such/program");
+        let mut stdout = Vec::new();
+
+        exec.execute(&mut stdout, &[]);
+
+        assert!(String::from_utf8(stdout)
+            .unwrap()
+            .starts_with("Failed to run /no/such/program:"));
+    }
 }
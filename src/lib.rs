@@ -16,20 +16,6 @@
 //! use rusty_cmd::command_handler::{CommandHandler, CommandResult};
 //! use rusty_cmd::handlers::Quit;
 //!
-//! /// CommandHandler that prints out help message
-//! #[derive(Default)]
-//! pub struct Help;
-//!
-//! impl<W> CommandHandler<W> for Help
-//! where
-//!     W: std::io::Write,
-//! {
-//!     fn execute(&self, output: &mut W, _args: &[&str]) -> CommandResult {
-//!         writeln!(output, "Help message").expect("Should be able to write to output");
-//!         CommandResult::Continue
-//!     }
-//! }
-//!
 //! /// CommandHandler that emulates the basic bash touch command to create a new file
 //! #[derive(Default)]
 //! pub struct Touch;
@@ -55,16 +41,20 @@
 //!         }
 //!         CommandResult::Continue
 //!     }
+//!
+//!     fn help(&self) -> &str {
+//!         "touch <filename>: Create a new, empty file"
+//!     }
 //! }
 //!
 //! fn main() -> Result<(), std::io::Error> {
 //!     let mut cmd = Cmd::new(io::BufReader::new(io::stdin()), io::stdout());
 //!
-//!     let help = Help;
 //!     let hello = Touch;
 //!     let quit = Quit::default();
 //!
-//!     cmd.add_cmd(String::from("help"), help)?;
+//!     // `help` is a built-in command, recognized by `Cmd::run` itself; no
+//!     // need to register a handler for it.
 //!     cmd.add_cmd(String::from("touch"), hello)?;
 //!     cmd.add_cmd_fn(String::from("greet"), |output, _args| {
 //!         writeln!(output, "hello!").expect("Should be able to write to output");
@@ -84,5 +74,8 @@ pub mod cmd;
 /// Contains the CommandHandler trait.
 pub mod command_handler;
 
+/// Contains the CommandSpec builder and Matches type for typed commands.
+pub mod command_spec;
+
 /// Contains common ready-to-use handlers
 pub mod handlers;